@@ -7,11 +7,23 @@
  *
 */
 extern crate bindgen;
+extern crate lvgl_build;
 use std::env;
 
 fn main() {
     // invalidate the built crate whenever the wrapper changes
     println!("cargo:rerun-if-changed=capi/capi-map.c");
+    println!("cargo:rerun-if-changed=capi/build.rs");
+
+    // png converted at build time for lv_conf.h color depth, use them with lvgl_image!("assets/xxx.png")
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let (depth, swap_16) = lvgl_build::color_depth_from_env();
+    if let Err(error) =
+        lvgl_build::convert_png("assets/tux-evsex150.png", out_dir.as_str(), depth, swap_16)
+    {
+        panic!("Fail to convert png assets: {}", error);
+    }
+
     println!("cargo:rustc-link-search=/usr/local/lib64");
     println!("cargo:rustc-link-arg=-llvgl");
     println!("cargo:rustc-link-arg=-llv_drivers");
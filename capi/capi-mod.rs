@@ -30,12 +30,12 @@ pub(crate) mod cglue {
 #[no_mangle]
 pub extern "C" fn lvgl_events_cb(event: *mut cglue::lv_event_t) {
     unsafe {
-        let _widget = cglue::lv_event_get_target(event);
+        let target = cglue::lv_event_get_target(event);
         let ctx = cglue::lv_event_get_user_data(event);
         let code = cglue::lv_event_get_code(event);
         let widget = &mut *(ctx as *mut LvglWidget);
 
-        widget.callback(&LvglEvent::from(code));
+        widget.callback(target, &LvglEvent::from(code));
     }
 }
 
@@ -0,0 +1,145 @@
+/*
+ * Copyright (C) 2015-2023 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Redpesk interface code/config use MIT License and can be freely copy/modified even within proprietary code
+ * License: $RP_BEGIN_LICENSE$ SPDX:MIT https://opensource.org/licenses/MIT $RP_END_LICENSE$
+ *
+*/
+
+// build.rs helper converting png into lvgl images, from any crate build.rs:
+//
+//   let (depth, swap) = lvgl_build::color_depth_from_env();
+//   lvgl_build::convert_png("assets/logo.png", &env::var("OUT_DIR").unwrap(), depth, swap).unwrap();
+//
+// then within crate sources: LvglPixmap::new(root, "logo", lvgl_image!("assets/logo.png"), 0, 0)
+extern crate png;
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// lvgl pixel formats, only one matching lv_conf.h is embedded
+#[derive(Clone, Copy)]
+enum ColorFormat {
+    Rgb332,
+    Rgb565,
+    Rgb565Swap,
+    Argb8888,
+}
+
+impl ColorFormat {
+    fn from_depth(depth: u8, swap_16: bool) -> Result<Self, String> {
+        match (depth, swap_16) {
+            (8, _) => Ok(ColorFormat::Rgb332),
+            (16, false) => Ok(ColorFormat::Rgb565),
+            (16, true) => Ok(ColorFormat::Rgb565Swap),
+            (32, _) => Ok(ColorFormat::Argb8888),
+            _ => Err(format!("unsupported LV_COLOR_DEPTH:{}", depth)),
+        }
+    }
+
+    // alpha byte is appended when image is not opaque
+    fn push_pixel(&self, rgba: &[u8], alpha: bool, output: &mut Vec<u8>) {
+        let (red, green, blue) = (rgba[0] as u16, rgba[1] as u16, rgba[2] as u16);
+        match self {
+            ColorFormat::Rgb332 => {
+                output.push(((red >> 5) << 5 | (green >> 5) << 2 | blue >> 6) as u8)
+            }
+            ColorFormat::Rgb565 | ColorFormat::Rgb565Swap => {
+                let color = (red >> 3) << 11 | (green >> 2) << 5 | blue >> 3;
+                if let ColorFormat::Rgb565 = self {
+                    output.extend_from_slice(&color.to_le_bytes());
+                } else {
+                    output.extend_from_slice(&color.to_be_bytes());
+                }
+            }
+            ColorFormat::Argb8888 => output.extend_from_slice(&[rgba[2], rgba[1], rgba[0], 0xFF]),
+        }
+        if alpha {
+            match self {
+                ColorFormat::Argb8888 => *output.last_mut().unwrap() = rgba[3],
+                _ => output.push(rgba[3]),
+            }
+        }
+    }
+}
+
+// LV_COLOR_DEPTH (default 32) and LV_COLOR_16_SWAP environment variables should match lv_conf.h
+pub fn color_depth_from_env() -> (u8, bool) {
+    println!("cargo:rerun-if-env-changed=LV_COLOR_DEPTH");
+    println!("cargo:rerun-if-env-changed=LV_COLOR_16_SWAP");
+    let depth = match env::var("LV_COLOR_DEPTH") {
+        Err(_) => 32,
+        Ok(value) => value.trim().parse().unwrap_or(0),
+    };
+    let swap_16 = match env::var("LV_COLOR_16_SWAP") {
+        Err(_) => false,
+        Ok(value) => value.trim() == "1",
+    };
+    (depth, swap_16)
+}
+
+fn png_to_rust(path: &str, depth: u8, swap_16: bool, format: ColorFormat) -> String {
+    let file = fs::File::open(path).unwrap_or_else(|_| panic!("Fail to open {}", path));
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder
+        .read_info()
+        .unwrap_or_else(|_| panic!("Invalid png {}", path));
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut buffer)
+        .unwrap_or_else(|_| panic!("Fail to decode {}", path));
+
+    // expand gray/rgb to rgba
+    let rgba: Vec<u8> = match info.color_type {
+        png::ColorType::Rgba => buffer[..info.buffer_size()].to_vec(),
+        png::ColorType::Rgb => buffer[..info.buffer_size()]
+            .chunks(3)
+            .flat_map(|px| [px[0], px[1], px[2], 0xFF])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buffer[..info.buffer_size()]
+            .chunks(2)
+            .flat_map(|px| [px[0], px[0], px[0], px[1]])
+            .collect(),
+        _ => buffer[..info.buffer_size()]
+            .iter()
+            .flat_map(|px| [*px, *px, *px, 0xFF])
+            .collect(),
+    };
+    let alpha = rgba.chunks(4).any(|px| px[3] != 0xFF);
+
+    let mut pixels = Vec::new();
+    for px in rgba.chunks(4) {
+        format.push_pixel(px, alpha, &mut pixels);
+    }
+
+    let mut source = format!(
+        "// generated by lvgl_build::convert_png from {}, do not edit\nLvglPngAsset::new(\n    {},\n    {},\n    {},\n    {},\n    {},\n    &[\n",
+        path, info.width, info.height, alpha, depth, swap_16
+    );
+    for line in pixels.chunks(16) {
+        source.push_str("       ");
+        for byte in line {
+            write!(source, " 0x{:02x},", byte).unwrap();
+        }
+        source.push('\n');
+    }
+    source.push_str("    ],\n)\n");
+    source
+}
+
+// write {out_dir}/{src}.rs for lv_conf.h color depth, lvgl_image!(src) includes it from crate OUT_DIR
+pub fn convert_png(src: &str, out_dir: &str, depth: u8, swap_16: bool) -> Result<PathBuf, String> {
+    let format = ColorFormat::from_depth(depth, swap_16)?;
+    println!("cargo:rerun-if-changed={}", src);
+    let output = Path::new(out_dir).join(format!("{}.rs", src.trim_start_matches('/')));
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)
+            .unwrap_or_else(|_| panic!("Fail to create {}", parent.display()));
+    }
+    fs::write(&output, png_to_rust(src, depth, swap_16, format))
+        .unwrap_or_else(|_| panic!("Couldn't write {}", output.display()));
+    Ok(output)
+}
@@ -0,0 +1,234 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::prelude::*;
+use std::mem;
+use std::os::raw;
+
+// widget property driven by animation, STYLE sets a numeric local style property
+#[derive(Clone, Copy)]
+pub enum LvglAnimProp {
+    X,
+    Y,
+    WIDTH,
+    HEIGHT,
+    OPA,
+    STYLE(LvglStyleProp, LvglSelector),
+}
+
+enum LvglAnimSetter {
+    Prop(LvglAnimProp),
+    Closure(Box<dyn Fn(i32)>),
+}
+
+// owned by lvgl animation, freed from anim_deleted_cb when animation ends or is cancelled
+struct LvglAnimCtx {
+    handle: *mut cglue::lv_obj_t,
+    setter: LvglAnimSetter,
+    path: cglue::lv_anim_path_cb_t,
+    ready: Option<Box<dyn Fn()>>,
+}
+
+impl LvglAnimCtx {
+    fn apply(&self, value: i32) {
+        let prop = match &self.setter {
+            LvglAnimSetter::Closure(callback) => return callback(value),
+            LvglAnimSetter::Prop(prop) => prop,
+        };
+        unsafe {
+            match prop {
+                LvglAnimProp::X => cglue::lv_obj_set_x(self.handle, value as cglue::lv_coord_t),
+                LvglAnimProp::Y => cglue::lv_obj_set_y(self.handle, value as cglue::lv_coord_t),
+                LvglAnimProp::WIDTH => {
+                    cglue::lv_obj_set_width(self.handle, value as cglue::lv_coord_t)
+                }
+                LvglAnimProp::HEIGHT => {
+                    cglue::lv_obj_set_height(self.handle, value as cglue::lv_coord_t)
+                }
+                LvglAnimProp::OPA => cglue::lv_obj_set_style_opa(self.handle, value as u8, 0),
+                LvglAnimProp::STYLE(prop, selector) => cglue::lv_obj_set_local_style_prop(
+                    self.handle,
+                    prop.get_raw(),
+                    cglue::lv_style_value_t { num: value },
+                    selector.handle,
+                ),
+            }
+        }
+    }
+}
+
+// exec_cb only receives lv_anim_t.var (the widget), value is applied from path_cb
+// where lv_anim_t.user_data gives access to animation context
+unsafe extern "C" fn anim_path_cb(anim: *const cglue::lv_anim_t) -> i32 {
+    let ctx = &*((*anim).user_data as *const LvglAnimCtx);
+    let value = match ctx.path {
+        Some(path) => path(anim),
+        None => cglue::lv_anim_path_linear(anim),
+    };
+    if value != (*anim).current_value {
+        ctx.apply(value);
+    }
+    value
+}
+
+// never applies value, it marks animations started from rust for cancellation
+unsafe extern "C" fn anim_exec_cb(_var: *mut raw::c_void, _value: i32) {}
+
+unsafe extern "C" fn anim_ready_cb(anim: *mut cglue::lv_anim_t) {
+    let ctx = &*((*anim).user_data as *const LvglAnimCtx);
+    if let Some(ready) = &ctx.ready {
+        ready();
+    }
+}
+
+unsafe extern "C" fn anim_deleted_cb(anim: *mut cglue::lv_anim_t) {
+    drop(Box::from_raw((*anim).user_data as *mut LvglAnimCtx));
+}
+
+// lv_anim_set_xxx are static inline, lv_anim_t fields are set directly
+pub struct LvglAnimation {
+    handle: cglue::lv_anim_t,
+    ctx: Box<LvglAnimCtx>,
+}
+
+impl LvglAnimation {
+    pub const INFINITE: u16 = cglue::LV_ANIM_REPEAT_INFINITE as u16;
+
+    fn create<W>(widget: &W, setter: LvglAnimSetter, start: i32, end: i32) -> Self
+    where
+        W: LvglCommon,
+    {
+        unsafe {
+            let mut handle = mem::zeroed::<cglue::lv_anim_t>();
+            cglue::lv_anim_init(&mut handle);
+            handle.var = widget.get_handle() as *mut raw::c_void;
+            handle.start_value = start;
+            handle.current_value = start;
+            handle.end_value = end;
+            handle.path_cb = Some(anim_path_cb);
+            handle.ready_cb = Some(anim_ready_cb);
+            handle.deleted_cb = Some(anim_deleted_cb);
+
+            LvglAnimation {
+                handle,
+                ctx: Box::new(LvglAnimCtx {
+                    handle: widget.get_handle(),
+                    setter,
+                    path: LvglEasing::LINEAR.get_path(),
+                    ready: None,
+                }),
+            }
+        }
+    }
+
+    pub fn new<W>(widget: &W, prop: LvglAnimProp, start: i32, end: i32) -> Self
+    where
+        W: LvglCommon,
+    {
+        LvglAnimation::create(widget, LvglAnimSetter::Prop(prop), start, end)
+    }
+
+    // closure is called with each intermediate value, widget is used for cancellation
+    pub fn with_setter<W, F>(widget: &W, start: i32, end: i32, setter: F) -> Self
+    where
+        W: LvglCommon,
+        F: Fn(i32) + 'static,
+    {
+        LvglAnimation::create(
+            widget,
+            LvglAnimSetter::Closure(Box::new(setter)),
+            start,
+            end,
+        )
+    }
+
+    pub fn set_duration(mut self, duration: u32) -> Self {
+        self.handle.time = duration as i32;
+        self
+    }
+
+    pub fn set_delay(mut self, delay: u32) -> Self {
+        self.handle.act_time = -(delay as i32);
+        self
+    }
+
+    pub fn set_easing(mut self, easing: LvglEasing) -> Self {
+        self.ctx.path = easing.get_path();
+        self
+    }
+
+    // play animation backward after each forward run
+    pub fn set_playback(mut self, duration: u32, delay: u32) -> Self {
+        self.handle.playback_time = duration;
+        self.handle.playback_delay = delay;
+        self
+    }
+
+    // total number of runs, LvglAnimation::INFINITE never stops
+    pub fn set_repeat(mut self, count: u16, delay: u32) -> Self {
+        self.handle.repeat_cnt = count;
+        self.handle.repeat_delay = delay;
+        self
+    }
+
+    // called once when the last run completes, not when animation is cancelled
+    pub fn set_ready_cb<F>(mut self, ready: F) -> Self
+    where
+        F: Fn() + 'static,
+    {
+        self.ctx.ready = Some(Box::new(ready));
+        self
+    }
+
+    pub fn start(mut self) {
+        self.handle.user_data = Box::into_raw(self.ctx) as *mut raw::c_void;
+        unsafe {
+            // start value is applied immediately unless animation is delayed
+            if self.handle.act_time >= 0 {
+                (*(self.handle.user_data as *const LvglAnimCtx)).apply(self.handle.start_value);
+            }
+            // marker is set once started, lv_anim_start deletes animations sharing var+exec_cb
+            let anim = cglue::lv_anim_start(&self.handle);
+            if anim.is_null() {
+                drop(Box::from_raw(self.handle.user_data as *mut LvglAnimCtx));
+            } else {
+                (*anim).exec_cb = Some(anim_exec_cb);
+            }
+        }
+    }
+
+    // cancel animations started by this api on widget, lvgl internal ones are kept
+    pub fn cancel<W>(widget: &W) -> bool
+    where
+        W: LvglCommon,
+    {
+        unsafe { cglue::lv_anim_del(widget.get_handle() as *mut raw::c_void, Some(anim_exec_cb)) }
+    }
+
+    pub fn cancel_all() {
+        unsafe { cglue::lv_anim_del(std::ptr::null_mut(), Some(anim_exec_cb)) };
+    }
+
+    pub fn is_running<W>(widget: &W) -> bool
+    where
+        W: LvglCommon,
+    {
+        unsafe {
+            !cglue::lv_anim_get(widget.get_handle() as *mut raw::c_void, Some(anim_exec_cb))
+                .is_null()
+        }
+    }
+}
@@ -16,6 +16,9 @@
  */
 
 use crate::prelude::*;
+use std::ffi::CString;
+use std::mem;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 
 macro_rules! impl_static_palette {
@@ -40,6 +43,27 @@ impl LvglColor {
         let handle = unsafe { cglue::lv_palette_main(palette) };
         LvglColor { handle }
     }
+
+    // "#RRGGBB" or "#RGB"
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let digits = hex.strip_prefix('#')?;
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        match digits.len() {
+            6 => Some(LvglColor::rvb((value >> 16) as u8, (value >> 8) as u8, value as u8)),
+            3 => Some(LvglColor::rvb(
+                ((value >> 8) & 0xF) as u8 * 0x11,
+                ((value >> 4) & 0xF) as u8 * 0x11,
+                (value & 0xF) as u8 * 0x11,
+            )),
+            _ => None,
+        }
+    }
+
+    // lvgl palette name as "DEEP_PURPLE"
+    pub fn from_name(name: &str) -> Option<Self> {
+        let palette = LvglPalette::from_name(name)?;
+        Some(LvglColor::palette(palette))
+    }
     impl_static_palette!(RED);
     impl_static_palette!(PINK);
     impl_static_palette!(PURPLE);
@@ -153,6 +177,32 @@ impl LvglPalette {
     pub const GREY: cglue::lv_palette_t = 18;
     pub const LAST: cglue::lv_palette_t = 19;
     pub const NONE: cglue::lv_palette_t = 255;
+
+    pub fn from_name(name: &str) -> Option<cglue::lv_palette_t> {
+        let palette = match name.to_uppercase().as_str() {
+            "RED" => LvglPalette::RED,
+            "PINK" => LvglPalette::PINK,
+            "PURPLE" => LvglPalette::PURPLE,
+            "DEEP_PURPLE" => LvglPalette::DEEP_PURPLE,
+            "INDIGO" => LvglPalette::INDIGO,
+            "BLUE" => LvglPalette::BLUE,
+            "LIGHT_BLUE" => LvglPalette::LIGHT_BLUE,
+            "CYAN" => LvglPalette::CYAN,
+            "TEAL" => LvglPalette::TEAL,
+            "GREEN" => LvglPalette::GREEN,
+            "LIGHT_GREEN" => LvglPalette::LIGHT_GREEN,
+            "LIME" => LvglPalette::LIME,
+            "YELLOW" => LvglPalette::YELLOW,
+            "AMBER" => LvglPalette::AMBER,
+            "ORANGE" => LvglPalette::ORANGE,
+            "DEEP_ORANGE" => LvglPalette::DEEP_ORANGE,
+            "BROWN" => LvglPalette::BROWN,
+            "BLUE_GREY" => LvglPalette::BLUE_GREY,
+            "GREY" => LvglPalette::GREY,
+            _ => return None,
+        };
+        Some(palette)
+    }
 }
 
 pub type LvglFont= cglue::lv_font_t;
@@ -176,4 +226,120 @@ impl LvglMkFont {
     impl_static_font! (std_34, lv_font_montserrat_34);
     impl_static_font! (std_40, lv_font_montserrat_40);
     impl_static_font! (std_48, lv_font_montserrat_48);
+
+    pub fn from_name(name: &str) -> Option<&'static LvglFont> {
+        let font = match name {
+            "std_10" => LvglMkFont::std_10(),
+            "std_12" => LvglMkFont::std_12(),
+            "std_14" => LvglMkFont::std_14(),
+            "std_18" => LvglMkFont::std_18(),
+            "std_22" => LvglMkFont::std_22(),
+            "std_26" => LvglMkFont::std_26(),
+            "std_30" => LvglMkFont::std_30(),
+            "std_34" => LvglMkFont::std_34(),
+            "std_40" => LvglMkFont::std_40(),
+            "std_48" => LvglMkFont::std_48(),
+            _ => return None,
+        };
+        Some(font)
+    }
+
+    // binary font generated with lv_font_conv --format bin, loaded through lvgl filesystem
+    pub fn from_file(path: &str) -> Result<&'static LvglFont, LvglError> {
+        let filepath = match CString::new(lvgl_path(path)) {
+            Err(_) => return Err(LvglError::InvalidPath(path.to_string())),
+            Ok(value) => value,
+        };
+        let font = unsafe { cglue::lv_font_load(filepath.as_ptr()) };
+        if font.is_null() {
+            return Err(LvglError::Decode(format!("{}: not a valid binary font", path)));
+        }
+        Ok(unsafe { &*font })
+    }
+}
+
+macro_rules! impl_static_image {
+    ($label:ident, $image:ident) => {
+        pub fn $label() -> &'static LvglImgDsc {
+            unsafe { &cglue::$image }
+        }
+    }
+}
+
+// images compiled within capi-map.c from assets/*.c
+pub struct LvglMkImage;
+impl LvglMkImage {
+    impl_static_image! (button_left, lv_button_left);
+    impl_static_image! (button_mid, lv_button_mid);
+    impl_static_image! (button_right, lv_button_right);
+}
+
+// png converted by lvgl_build::convert_png for a single color depth, assets converted for
+// another depth than lv_conf.h LV_COLOR_DEPTH/LV_COLOR_16_SWAP fail at compile time
+pub struct LvglPngAsset {
+    width: u32,
+    height: u32,
+    alpha: bool,
+    map: &'static [u8],
+    dsc: AtomicPtr<LvglImgDsc>,
+}
+
+impl LvglPngAsset {
+    #[doc(hidden)]
+    pub const fn new(
+        width: u32,
+        height: u32,
+        alpha: bool,
+        depth: u32,
+        swap_16: bool,
+        map: &'static [u8],
+    ) -> Self {
+        assert!(
+            depth == cglue::LV_COLOR_DEPTH
+                && (depth != 16 || swap_16 == (cglue::LV_COLOR_16_SWAP != 0)),
+            "png asset color depth does not match lv_conf.h, check LV_COLOR_DEPTH/LV_COLOR_16_SWAP"
+        );
+        LvglPngAsset {
+            width,
+            height,
+            alpha,
+            map,
+            dsc: AtomicPtr::new(std::ptr::null_mut()),
+        }
+    }
+
+    pub fn get_dsc(&'static self) -> &'static LvglImgDsc {
+        let dsc = self.dsc.load(Ordering::Relaxed);
+        if !dsc.is_null() {
+            return unsafe { &*dsc };
+        }
+
+        let cf = if self.alpha {
+            cglue::LV_IMG_CF_TRUE_COLOR_ALPHA
+        } else {
+            cglue::LV_IMG_CF_TRUE_COLOR
+        };
+        unsafe {
+            let mut dsc = Box::new(mem::zeroed::<LvglImgDsc>());
+            dsc.header.set_cf(cf);
+            dsc.header.set_w(self.width);
+            dsc.header.set_h(self.height);
+            dsc.data_size = self.map.len() as u32;
+            dsc.data = self.map.as_ptr();
+            let dsc = Box::into_raw(dsc);
+            self.dsc.store(dsc, Ordering::Relaxed);
+            &*dsc
+        }
+    }
+}
+
+// lvgl_image!("assets/tux-evsex150.png") -> &'static LvglImgDsc, the path should be converted by
+// calling lvgl_build::convert_png("assets/tux-evsex150.png", OUT_DIR, ...) from the crate build.rs
+#[macro_export]
+macro_rules! lvgl_image {
+    ($path:literal) => {{
+        use $crate::prelude::LvglPngAsset;
+        static ASSET: LvglPngAsset = include!(concat!(env!("OUT_DIR"), "/", $path, ".rs"));
+        ASSET.get_dsc()
+    }};
 }
@@ -17,10 +17,11 @@
 use crate::impl_widget_trait;
 use crate::prelude::*;
 use std::any::Any;
-use std::cell::Cell;
-use std::ffi::CString;
+use std::cell::{Cell, RefCell};
+use std::ffi::{CStr, CString};
 use std::mem;
 use std::os::raw;
+use std::rc::Rc;
 
 pub struct LvglButton {
     uid: &'static str,
@@ -167,6 +168,7 @@ pub struct LvglPixButton {
     handle: *mut cglue::_lv_obj_t,
     image: *mut cglue::_lv_obj_t,
     style: *mut cglue::lv_style_t,
+    buffer: LvglImageSlot,
     ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
 }
 
@@ -190,6 +192,7 @@ impl LvglPixButton {
                 handle,
                 style,
                 image,
+                buffer: RefCell::new(None),
                 ctrlbox: Cell::new(None),
             };
             Box::leak(Box::new(widget))
@@ -200,6 +203,7 @@ impl LvglPixButton {
     where
         LvglPixmap: ImgToVoid<T>,
     {
+        LvglImageBuffer::detach(self.image, &self.buffer);
         let imgref = LvglPixmap::get_ref(pixmap);
         unsafe {
             cglue::lv_img_set_src(self.image, imgref);
@@ -207,6 +211,11 @@ impl LvglPixButton {
         self
     }
 
+    pub fn set_buffer(&'static self, buffer: &Rc<LvglImageBuffer>) -> &Self {
+        LvglImageBuffer::attach(buffer, self.image, &self.buffer);
+        self
+    }
+
     pub fn get_action(&self) -> &'static str {
         &"['ON','OFF']"
     }
@@ -239,11 +248,212 @@ impl ImgToVoid<&LvglImgDsc> for LvglPixmap {
     }
 }
 
+// rust owned pixels, widgets showing the buffer keep it alive and are redrawn on update
+pub struct LvglImageBuffer {
+    dsc: LvglImgDsc,
+    format: LvglColorFormat,
+    pixels: RefCell<Vec<u8>>,
+    widgets: RefCell<Vec<*mut cglue::_lv_obj_t>>,
+}
+
+type LvglImageSlot = RefCell<Option<Rc<LvglImageBuffer>>>;
+
+// lv_img_header_t stores width and height on 11 bits
+const IMG_BUFFER_MAX_SIZE: u16 = 2047;
+
+// widget deleted, release its buffer reference
+extern "C" fn image_buffer_delete_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let handle = cglue::lv_event_get_target(event);
+        let slot = &*(cglue::lv_event_get_user_data(event) as *const LvglImageSlot);
+        if let Some(buffer) = slot.take() {
+            buffer
+                .widgets
+                .borrow_mut()
+                .retain(|widget| *widget != handle);
+        }
+    }
+}
+
+impl LvglImageBuffer {
+    pub fn new(width: u16, height: u16, format: LvglColorFormat) -> Result<Rc<Self>, LvglError> {
+        let size = LvglImageBuffer::get_size(width, height, format)?;
+        Ok(LvglImageBuffer::create(
+            width,
+            height,
+            format,
+            vec![0; size],
+        ))
+    }
+
+    pub fn from_data(
+        width: u16,
+        height: u16,
+        format: LvglColorFormat,
+        data: Vec<u8>,
+    ) -> Result<Rc<Self>, LvglError> {
+        let size = LvglImageBuffer::get_size(width, height, format)?;
+        if data.len() != size {
+            return Err(LvglError::BufferSize(size, data.len()));
+        }
+        Ok(LvglImageBuffer::create(width, height, format, data))
+    }
+
+    fn get_size(width: u16, height: u16, format: LvglColorFormat) -> Result<usize, LvglError> {
+        if width > IMG_BUFFER_MAX_SIZE || height > IMG_BUFFER_MAX_SIZE {
+            return Err(LvglError::Unsupported(format!(
+                "image buffer size:{}x{} max:{}",
+                width, height, IMG_BUFFER_MAX_SIZE
+            )));
+        }
+        let size = unsafe {
+            cglue::lv_img_buf_get_img_size(width as i16, height as i16, format.get_raw())
+        };
+        Ok(size as usize)
+    }
+
+    fn create(width: u16, height: u16, format: LvglColorFormat, mut data: Vec<u8>) -> Rc<Self> {
+        let mut dsc = unsafe { mem::zeroed::<LvglImgDsc>() };
+        dsc.header.set_cf(format.get_raw() as u32);
+        dsc.header.set_w(width as u32);
+        dsc.header.set_h(height as u32);
+        dsc.data_size = data.len() as u32;
+
+        // vector is never resized, data pointer remains valid for buffer lifetime
+        dsc.data = data.as_mut_ptr();
+        Rc::new(LvglImageBuffer {
+            dsc,
+            format,
+            pixels: RefCell::new(data),
+            widgets: RefCell::new(Vec::new()),
+        })
+    }
+
+    pub fn get_width(&self) -> u16 {
+        self.dsc.header.w() as u16
+    }
+
+    pub fn get_height(&self) -> u16 {
+        self.dsc.header.h() as u16
+    }
+
+    pub fn get_format(&self) -> LvglColorFormat {
+        self.format
+    }
+
+    pub fn set_pixels(&self, data: &[u8]) -> Result<&Self, LvglError> {
+        {
+            let mut pixels = self.pixels.borrow_mut();
+            if data.len() != pixels.len() {
+                return Err(LvglError::BufferSize(pixels.len(), data.len()));
+            }
+            pixels.copy_from_slice(data);
+        }
+        self.refresh();
+        Ok(self)
+    }
+
+    // raw access in lvgl color format (LV_COLOR_DEPTH), widgets are redrawn when closure returns
+    pub fn update<F>(&self, writer: F) -> &Self
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        writer(self.pixels.borrow_mut().as_mut_slice());
+        self.refresh();
+        self
+    }
+
+    // lvgl does not check pixel position, pixels outside of buffer are ignored
+    pub fn set_pixel(&self, x: i16, y: i16, color: LvglColor) -> &Self {
+        if x < 0 || y < 0 || x >= self.get_width() as i16 || y >= self.get_height() as i16 {
+            return self;
+        }
+        unsafe {
+            cglue::lv_img_buf_set_px_color(self.get_dsc(), x, y, color.handle);
+        }
+        self.refresh();
+        self
+    }
+
+    pub fn fill(&self, color: LvglColor, opacity: u8) -> &Self {
+        let dsc = self.get_dsc();
+        unsafe {
+            for y in 0..self.get_height() as i16 {
+                for x in 0..self.get_width() as i16 {
+                    cglue::lv_img_buf_set_px_color(dsc, x, y, color.handle);
+                    if self.format != LvglColorFormat::TRUE_COLOR {
+                        cglue::lv_img_buf_set_px_alpha(dsc, x, y, opacity);
+                    }
+                }
+            }
+        }
+        self.refresh();
+        self
+    }
+
+    fn get_dsc(&self) -> *mut LvglImgDsc {
+        &self.dsc as *const _ as *mut LvglImgDsc
+    }
+
+    // drop lvgl cached decoding and redraw every widget showing the buffer
+    fn refresh(&self) {
+        unsafe {
+            cglue::lv_img_cache_invalidate_src(self.get_dsc() as *const raw::c_void);
+            for handle in self.widgets.borrow().iter() {
+                cglue::lv_obj_invalidate(*handle);
+            }
+        }
+    }
+
+    pub(crate) fn attach(
+        buffer: &Rc<Self>,
+        handle: *mut cglue::_lv_obj_t,
+        slot: &'static LvglImageSlot,
+    ) {
+        match slot.replace(Some(buffer.clone())) {
+            Some(previous) => previous
+                .widgets
+                .borrow_mut()
+                .retain(|widget| *widget != handle),
+            None => unsafe {
+                cglue::lv_obj_add_event_cb(
+                    handle,
+                    Some(image_buffer_delete_cb),
+                    cglue::lv_event_code_t_LV_EVENT_DELETE,
+                    slot as *const _ as *mut raw::c_void,
+                );
+            },
+        }
+        buffer.widgets.borrow_mut().push(handle);
+        unsafe {
+            cglue::lv_img_set_src(handle, buffer.get_dsc() as *const raw::c_void);
+        }
+    }
+
+    // widget switched back to a static image
+    pub(crate) fn detach(handle: *mut cglue::_lv_obj_t, slot: &LvglImageSlot) {
+        if let Some(buffer) = slot.take() {
+            buffer
+                .widgets
+                .borrow_mut()
+                .retain(|widget| *widget != handle);
+            unsafe {
+                cglue::lv_obj_remove_event_cb_with_user_data(
+                    handle,
+                    Some(image_buffer_delete_cb),
+                    slot as *const _ as *mut raw::c_void,
+                );
+            }
+        }
+    }
+}
+
 pub struct LvglPixmap {
     uid: &'static str,
     info: Cell<&'static str>,
     handle: *mut cglue::_lv_obj_t,
     style: *mut cglue::lv_style_t,
+    buffer: LvglImageSlot,
     ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
 }
 
@@ -274,6 +484,7 @@ impl LvglPixmap {
                 info: Cell::new(""),
                 handle,
                 style,
+                buffer: RefCell::new(None),
                 ctrlbox: Cell::new(None),
             };
             Box::leak(Box::new(widget))
@@ -288,6 +499,7 @@ impl LvglPixmap {
     where
         Self: ImgToVoid<T>,
     {
+        LvglImageBuffer::detach(self.handle, &self.buffer);
         let imgref = LvglPixmap::get_ref(pixmap);
         unsafe {
             cglue::lv_img_set_src(self.handle, imgref);
@@ -295,6 +507,11 @@ impl LvglPixmap {
         self
     }
 
+    pub fn set_buffer(&'static self, buffer: &Rc<LvglImageBuffer>) -> &Self {
+        LvglImageBuffer::attach(buffer, self.handle, &self.buffer);
+        self
+    }
+
     pub fn set_angle(&self, rotation: i16) -> &Self {
         unsafe {
             cglue::lv_img_set_angle(self.handle, rotation);
@@ -315,6 +532,7 @@ pub struct LvglImage {
     info: Cell<&'static str>,
     handle: *mut cglue::_lv_obj_t,
     style: *mut cglue::lv_style_t,
+    source: RefCell<Option<Box<LvglImgDsc>>>,
     ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
 }
 impl_widget_trait!(LvglImage, Image);
@@ -325,17 +543,33 @@ impl LvglImage {
         path: &str,
         x_ofs: i16,
         y_ofs: i16,
-    ) -> &'static Self {
-        let mut img_path = path.to_string();
-        img_path.insert_str(0, "L:"); // ugly lvgl path pattern
-        let filepath = match CString::new(img_path) {
-            Err(_) => CString::new("Non UTF8 path").unwrap(),
-            Ok(value) => value,
-        };
+    ) -> Result<&'static Self, LvglError> {
+        // validate source first, a failing image should not leave an empty widget behind
+        let filepath = LvglImage::path_src(path)?;
+        let widget = LvglImage::create(parent, uid, x_ofs, y_ofs);
+        unsafe {
+            cglue::lv_img_set_src(widget.handle, filepath.as_ptr() as *const raw::c_void);
+        }
+        Ok(widget)
+    }
+
+    // png/jpeg image held in memory, lvgl decodes it from the buffer when drawing
+    pub fn from_data(
+        parent: &LvglWidget,
+        uid: &'static str,
+        data: &'static [u8],
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> Result<&'static Self, LvglError> {
+        let dsc = LvglImage::data_src(data)?;
+        let widget = LvglImage::create(parent, uid, x_ofs, y_ofs);
+        widget.attach_data(dsc);
+        Ok(widget)
+    }
 
+    fn create(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
         unsafe {
             let handle = cglue::lv_img_create(parent.get_handle());
-            cglue::lv_img_set_src(handle, filepath.as_ptr() as *mut raw::c_void);
             cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
 
             let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
@@ -347,23 +581,72 @@ impl LvglImage {
                 info: Cell::new(""),
                 handle,
                 style,
+                source: RefCell::new(None),
                 ctrlbox: Cell::new(None),
             };
             Box::leak(Box::new(widget))
         }
     }
-    pub fn set_value<T>(&self, path: &str) -> &Self {
-        let mut img_path = path.to_string();
-        img_path.insert_str(0, "L:"); // ugly lvgl path pattern
-        let filepath = match CString::new(img_path) {
-            Err(_) => CString::new("Non UTF8 path").unwrap(),
+
+    // ask registered decoders (native, png, bmp, sjpg) if they can handle the source
+    fn check_src(src: *const raw::c_void) -> Result<(), LvglError> {
+        unsafe {
+            let mut header = mem::zeroed::<cglue::lv_img_header_t>();
+            if cglue::lv_img_decoder_get_info(src, &mut header) != cglue::LV_RES_OK as u8 {
+                return Err(LvglError::Decode("no decoder accepts image".to_string()));
+            }
+            if header.w() == 0 || header.h() == 0 {
+                return Err(LvglError::Decode("empty image".to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    fn path_src(path: &str) -> Result<CString, LvglError> {
+        let filepath = match CString::new(lvgl_path(path)) {
+            Err(_) => return Err(LvglError::InvalidPath(path.to_string())),
             Ok(value) => value,
         };
 
+        let src = filepath.as_ptr() as *const raw::c_void;
+        if let Err(LvglError::Decode(error)) = LvglImage::check_src(src) {
+            return Err(LvglError::Decode(format!("{}: {}", path, error)));
+        }
+        Ok(filepath)
+    }
+
+    fn data_src(data: &'static [u8]) -> Result<Box<LvglImgDsc>, LvglError> {
+        let dsc = unsafe {
+            let mut dsc = Box::new(mem::zeroed::<LvglImgDsc>());
+            dsc.header.set_cf(cglue::LV_IMG_CF_RAW_ALPHA);
+            dsc.data_size = data.len() as u32;
+            dsc.data = data.as_ptr();
+            dsc
+        };
+        LvglImage::check_src(&*dsc as *const _ as *const raw::c_void)?;
+        Ok(dsc)
+    }
+
+    fn attach_data(&self, dsc: Box<LvglImgDsc>) {
         unsafe {
-            cglue::lv_img_set_src(self.handle, filepath.as_ptr() as *mut raw::c_void);
+            cglue::lv_img_set_src(self.handle, &*dsc as *const _ as *const raw::c_void);
         }
-        self
+        *self.source.borrow_mut() = Some(dsc);
+    }
+
+    pub fn set_value(&self, path: &str) -> Result<&Self, LvglError> {
+        let filepath = LvglImage::path_src(path)?;
+        unsafe {
+            cglue::lv_img_set_src(self.handle, filepath.as_ptr() as *const raw::c_void);
+        }
+        Ok(self)
+    }
+
+    // bmp decoder only supports files, use set_value for them
+    pub fn set_data(&self, data: &'static [u8]) -> Result<&Self, LvglError> {
+        let dsc = LvglImage::data_src(data)?;
+        self.attach_data(dsc);
+        Ok(self)
     }
 
     pub fn set_angle(&self, rotation: i16) -> &Self {
@@ -757,16 +1040,27 @@ impl LvglBar {
     }
 
     pub fn set_gradient(&self, vertical: bool, color: LvglColor, background: LvglColor) -> &Self {
+        self.set_gradient_for(vertical, color, background, LvglPart::INDICATOR)
+    }
+
+    pub fn set_gradient_for(
+        &self,
+        vertical: bool,
+        color: LvglColor,
+        background: LvglColor,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self {
+        let selector = selector.into().handle;
+        let direction = if vertical {
+            cglue::LV_GRAD_DIR_VER
+        } else {
+            cglue::LV_GRAD_DIR_HOR
+        };
         unsafe {
-            cglue::lv_style_set_bg_opa(self.style, cglue::LV_OPA_COVER as u8);
-            cglue::lv_style_set_bg_color(self.style, background.handle);
-            cglue::lv_style_set_bg_grad_color(self.style, color.handle);
-            if vertical {
-                cglue::lv_style_set_bg_grad_dir(self.style, cglue::LV_GRAD_DIR_VER as u8);
-            } else {
-                cglue::lv_style_set_bg_grad_dir(self.style, cglue::LV_GRAD_DIR_HOR as u8);
-            }
-            cglue::lv_obj_add_style(self.handle, self.style, cglue::LV_PART_INDICATOR);
+            cglue::lv_obj_set_style_bg_opa(self.handle, cglue::LV_OPA_COVER as u8, selector);
+            cglue::lv_obj_set_style_bg_color(self.handle, background.handle, selector);
+            cglue::lv_obj_set_style_bg_grad_color(self.handle, color.handle, selector);
+            cglue::lv_obj_set_style_bg_grad_dir(self.handle, direction as u8, selector);
         }
         self
     }
@@ -822,3 +1116,363 @@ impl LvglArea {
         }
     }
 }
+
+pub struct LvglTable {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglTable, Table);
+impl LvglTable {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        rows: u16,
+        columns: u16,
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_table_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+            cglue::lv_table_set_row_cnt(handle, rows);
+            cglue::lv_table_set_col_cnt(handle, columns);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglTable {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    pub fn set_rows(&self, count: u16) -> &Self {
+        unsafe {
+            cglue::lv_table_set_row_cnt(self.handle, count);
+        }
+        self
+    }
+
+    pub fn set_columns(&self, count: u16) -> &Self {
+        unsafe {
+            cglue::lv_table_set_col_cnt(self.handle, count);
+        }
+        self
+    }
+
+    pub fn get_rows(&self) -> u16 {
+        unsafe { cglue::lv_table_get_row_cnt(self.handle) }
+    }
+
+    pub fn get_columns(&self) -> u16 {
+        unsafe { cglue::lv_table_get_col_cnt(self.handle) }
+    }
+
+    pub fn set_col_width(&self, column: u16, width: i16) -> &Self {
+        unsafe {
+            cglue::lv_table_set_col_width(self.handle, column, width);
+        }
+        self
+    }
+
+    // table grows automatically when row/column is out of range
+    pub fn set_cell(&self, row: u16, column: u16, text: &str) -> &Self {
+        unsafe {
+            let text = match CString::new(text) {
+                Err(_) => CString::new("Non UTF8 text").unwrap(),
+                Ok(value) => value,
+            };
+            cglue::lv_table_set_cell_value(self.handle, row, column, text.as_ptr());
+        }
+        self
+    }
+
+    pub fn get_cell(&self, row: u16, column: u16) -> Option<String> {
+        unsafe {
+            let text = cglue::lv_table_get_cell_value(self.handle, row, column);
+            if text.is_null() {
+                return None;
+            }
+            match CStr::from_ptr(text).to_str() {
+                Err(_) => None,
+                Ok(value) => Some(value.to_owned()),
+            }
+        }
+    }
+
+    // resize table to data and fill every cells in one call
+    pub fn set_values(&self, rows: &[Vec<String>]) -> &Self {
+        let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        unsafe {
+            cglue::lv_table_set_row_cnt(self.handle, rows.len() as u16);
+            cglue::lv_table_set_col_cnt(self.handle, columns as u16);
+        }
+        for (row_idx, row) in rows.iter().enumerate() {
+            for col_idx in 0..columns {
+                let text = match row.get(col_idx) {
+                    Some(value) => value.as_str(),
+                    None => "",
+                };
+                self.set_cell(row_idx as u16, col_idx as u16, text);
+            }
+        }
+        self
+    }
+
+    pub fn add_cell_ctrl(&self, row: u16, column: u16, ctrl: LvglTableCtrl) -> &Self {
+        unsafe {
+            cglue::lv_table_add_cell_ctrl(self.handle, row, column, ctrl.get_raw());
+        }
+        self
+    }
+
+    pub fn clear_cell_ctrl(&self, row: u16, column: u16, ctrl: LvglTableCtrl) -> &Self {
+        unsafe {
+            cglue::lv_table_clear_cell_ctrl(self.handle, row, column, ctrl.get_raw());
+        }
+        self
+    }
+
+    pub fn has_cell_ctrl(&self, row: u16, column: u16, ctrl: LvglTableCtrl) -> bool {
+        unsafe { cglue::lv_table_has_cell_ctrl(self.handle, row, column, ctrl.get_raw()) }
+    }
+
+    // return (row, column) of last pressed cell
+    pub fn get_selected_cell(&self) -> Option<(u16, u16)> {
+        let mut row: u16 = 0;
+        let mut column: u16 = 0;
+        unsafe {
+            cglue::lv_table_get_selected_cell(self.handle, &mut row, &mut column);
+        }
+        if row == cglue::LV_TABLE_CELL_NONE as u16 || column == cglue::LV_TABLE_CELL_NONE as u16 {
+            None
+        } else {
+            Some((row, column))
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::CLICKED => {}
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglCanvas {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    buffer: RefCell<Vec<u8>>,
+    size: Cell<(i16, i16)>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// canvas buffer is owned by the widget and released with lvgl object
+extern "C" fn canvas_delete_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let canvas = &*(cglue::lv_event_get_user_data(event) as *const LvglCanvas);
+        *canvas.buffer.borrow_mut() = Vec::new();
+        canvas.size.set((0, 0));
+    }
+}
+
+impl_widget_trait!(LvglCanvas, Canvas);
+impl LvglCanvas {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        width: i16,
+        height: i16,
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_canvas_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = Box::leak(Box::new(LvglCanvas {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                buffer: RefCell::new(Vec::new()),
+                size: Cell::new((0, 0)),
+                ctrlbox: Cell::new(None),
+            }));
+            widget.set_buffer(width, height);
+
+            cglue::lv_obj_add_event_cb(
+                handle,
+                Some(canvas_delete_cb),
+                cglue::lv_event_code_t_LV_EVENT_DELETE,
+                widget as *const _ as *mut raw::c_void,
+            );
+            widget
+        }
+    }
+
+    // reallocate drawing buffer, previous drawing is lost
+    pub fn set_buffer(&self, width: i16, height: i16) -> &Self {
+        let cf = cglue::LV_IMG_CF_TRUE_COLOR as u8;
+        unsafe {
+            let size = cglue::lv_img_buf_get_img_size(width, height, cf) as usize;
+            let mut buffer = self.buffer.borrow_mut();
+            *buffer = vec![0; size];
+            cglue::lv_canvas_set_buffer(
+                self.handle,
+                buffer.as_mut_ptr() as *mut raw::c_void,
+                width,
+                height,
+                cf,
+            );
+        }
+        self.size.set((width, height));
+        self
+    }
+
+    // lvgl does not check pixel position against canvas buffer
+    fn contains(&self, x: i16, y: i16) -> bool {
+        let (width, height) = self.size.get();
+        x >= 0 && y >= 0 && x < width && y < height
+    }
+
+    pub fn fill(&self, color: LvglColor) -> &Self {
+        unsafe {
+            cglue::lv_canvas_fill_bg(self.handle, color.handle, cglue::LV_OPA_COVER as u8);
+        }
+        self
+    }
+
+    // pixels outside of canvas are ignored
+    pub fn set_pixel(&self, x: i16, y: i16, color: LvglColor) -> &Self {
+        if self.contains(x, y) {
+            unsafe {
+                cglue::lv_canvas_set_px_color(self.handle, x, y, color.handle);
+            }
+        }
+        self
+    }
+
+    pub fn get_pixel(&self, x: i16, y: i16) -> Option<LvglColor> {
+        if !self.contains(x, y) {
+            return None;
+        }
+        let handle = unsafe { cglue::lv_canvas_get_px(self.handle, x, y) };
+        Some(LvglColor { handle })
+    }
+
+    pub fn draw_rect(
+        &self,
+        x: i16,
+        y: i16,
+        width: i16,
+        height: i16,
+        radius: i16,
+        color: LvglColor,
+    ) -> &Self {
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_rect_dsc_t>();
+            cglue::lv_draw_rect_dsc_init(&mut dsc);
+            dsc.bg_color = color.handle;
+            dsc.radius = radius;
+            cglue::lv_canvas_draw_rect(self.handle, x, y, width, height, &dsc);
+        }
+        self
+    }
+
+    pub fn draw_line(&self, points: &[LvglPoint], width: i16, color: LvglColor) -> &Self {
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_line_dsc_t>();
+            cglue::lv_draw_line_dsc_init(&mut dsc);
+            dsc.color = color.handle;
+            dsc.width = width;
+            cglue::lv_canvas_draw_line(self.handle, points.as_ptr(), points.len() as u32, &dsc);
+        }
+        self
+    }
+
+    pub fn draw_polygon(&self, points: &[LvglPoint], color: LvglColor) -> &Self {
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_rect_dsc_t>();
+            cglue::lv_draw_rect_dsc_init(&mut dsc);
+            dsc.bg_color = color.handle;
+            cglue::lv_canvas_draw_polygon(self.handle, points.as_ptr(), points.len() as u32, &dsc);
+        }
+        self
+    }
+
+    pub fn draw_arc(
+        &self,
+        x: i16,
+        y: i16,
+        radius: i16,
+        angle_start: i32,
+        angle_end: i32,
+        width: i16,
+        color: LvglColor,
+    ) -> &Self {
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_arc_dsc_t>();
+            cglue::lv_draw_arc_dsc_init(&mut dsc);
+            dsc.color = color.handle;
+            dsc.width = width;
+            cglue::lv_canvas_draw_arc(self.handle, x, y, radius, angle_start, angle_end, &dsc);
+        }
+        self
+    }
+
+    pub fn draw_text(
+        &self,
+        x: i16,
+        y: i16,
+        max_width: i16,
+        text: &str,
+        font: &LvglFont,
+        color: LvglColor,
+    ) -> &Self {
+        let text = match CString::new(text) {
+            Err(_) => CString::new("Non UTF8 text").unwrap(),
+            Ok(value) => value,
+        };
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_label_dsc_t>();
+            cglue::lv_draw_label_dsc_init(&mut dsc);
+            dsc.color = color.handle;
+            dsc.font = font as *const _ as *const cglue::lv_font_t;
+            cglue::lv_canvas_draw_text(self.handle, x, y, max_width, &dsc, text.as_ptr());
+        }
+        self
+    }
+
+    pub fn draw_image<T>(&self, x: i16, y: i16, image: T) -> &Self
+    where
+        LvglPixmap: ImgToVoid<T>,
+    {
+        let imgref = LvglPixmap::get_ref(image);
+        unsafe {
+            let mut dsc = mem::zeroed::<cglue::lv_draw_img_dsc_t>();
+            cglue::lv_draw_img_dsc_init(&mut dsc);
+            cglue::lv_canvas_draw_img(self.handle, x, y, imgref, &dsc);
+        }
+        self
+    }
+}
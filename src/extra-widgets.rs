@@ -16,8 +16,10 @@
  */
 use crate::impl_widget_trait;
 use std::any::Any;
-use std::cell::Cell;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::ffi::{CStr, CString};
 use std::mem;
+use std::ops::Deref;
 use std::os::raw;
 use crate::prelude::*;
 
@@ -188,3 +190,1696 @@ impl LvglQrcode {
         self
     }
 }
+
+pub struct LvglChart {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    series: RefCell<Vec<*mut cglue::lv_chart_series_t>>,
+    ranges: Cell<[(i16, i16); 4]>,
+    labels: RefCell<[Vec<CString>; 4]>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// replace chart major tick text with user defined labels
+extern "C" fn chart_labels_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let chart = &*(cglue::lv_event_get_user_data(event) as *const LvglChart);
+        let dsc = &mut *cglue::lv_event_get_draw_part_dsc(event);
+        if dsc.part != cglue::LV_PART_TICKS || dsc.text.is_null() || dsc.id > 4 {
+            return;
+        }
+
+        // axis flags are 0,1,2,4
+        let axis = if dsc.id == 4 { 3 } else { dsc.id as usize };
+        let labels = chart.labels.borrow();
+        let labels = &labels[axis];
+        if labels.is_empty() {
+            return;
+        }
+
+        // x ticks value is the major index, y ticks value is mapped on axis range
+        let index = if axis < 2 {
+            let (min, max) = chart.ranges.get()[axis];
+            if max == min {
+                return;
+            }
+            (dsc.value - min as i32) * (labels.len() as i32 - 1) / (max as i32 - min as i32)
+        } else {
+            dsc.value
+        };
+        if index < 0 || index as usize >= labels.len() {
+            return;
+        }
+
+        let text = labels[index as usize].as_bytes_with_nul();
+        let count = text.len().min(dsc.text_length as usize);
+        if count == 0 {
+            return;
+        }
+        std::ptr::copy_nonoverlapping(text.as_ptr() as *const raw::c_char, dsc.text, count);
+        *dsc.text.add(count - 1) = 0;
+    }
+}
+
+impl_widget_trait!(LvglChart, Chart);
+impl LvglChart {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        chart_type: LvglChartType,
+        point_count: u16,
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_chart_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+            cglue::lv_chart_set_type(handle, chart_type.get_raw());
+            cglue::lv_chart_set_point_count(handle, point_count);
+            cglue::lv_chart_set_update_mode(handle, cglue::LV_CHART_UPDATE_MODE_SHIFT as u8);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = Box::leak(Box::new(LvglChart {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                series: RefCell::new(Vec::new()),
+                ranges: Cell::new([(0, 100); 4]),
+                labels: RefCell::new([Vec::new(), Vec::new(), Vec::new(), Vec::new()]),
+                ctrlbox: Cell::new(None),
+            }));
+
+            cglue::lv_obj_add_event_cb(
+                handle,
+                Some(chart_labels_cb),
+                cglue::lv_event_code_t_LV_EVENT_DRAW_PART_BEGIN,
+                widget as *const _ as *mut raw::c_void,
+            );
+            widget
+        }
+    }
+
+    pub fn set_type(&self, chart_type: LvglChartType) -> &Self {
+        unsafe {
+            cglue::lv_chart_set_type(self.handle, chart_type.get_raw());
+        }
+        self
+    }
+
+    pub fn set_point_count(&self, count: u16) -> &Self {
+        unsafe {
+            cglue::lv_chart_set_point_count(self.handle, count);
+        }
+        self
+    }
+
+    // shift: new points push old ones to the left, otherwise points are overwritten circularly
+    pub fn set_shift(&self, shift: bool) -> &Self {
+        let mode = if shift {
+            cglue::LV_CHART_UPDATE_MODE_SHIFT
+        } else {
+            cglue::LV_CHART_UPDATE_MODE_CIRCULAR
+        };
+        unsafe {
+            cglue::lv_chart_set_update_mode(self.handle, mode as u8);
+        }
+        self
+    }
+
+    pub fn set_range(&self, axis: LvglChartAxis, min: i16, max: i16) -> &Self {
+        let mut ranges = self.ranges.get();
+        ranges[axis.get_index()] = (min, max);
+        self.ranges.set(ranges);
+        unsafe {
+            cglue::lv_chart_set_range(self.handle, axis.get_raw(), min, max);
+        }
+        self
+    }
+
+    pub fn set_div_lines(&self, horizontal: u8, vertical: u8) -> &Self {
+        unsafe {
+            cglue::lv_chart_set_div_line_count(self.handle, horizontal, vertical);
+        }
+        self
+    }
+
+    pub fn set_axis_tick(
+        &self,
+        axis: LvglChartAxis,
+        major_len: i16,
+        minor_len: i16,
+        major_count: i16,
+        minor_count: i16, // minor ticks between two major
+        label: bool,
+        draw_size: i16, // extra space reserved for ticks and labels
+    ) -> &Self {
+        unsafe {
+            cglue::lv_chart_set_axis_tick(
+                self.handle,
+                axis.get_raw(),
+                major_len,
+                minor_len,
+                major_count,
+                minor_count,
+                label,
+                draw_size,
+            );
+        }
+        self
+    }
+
+    // replace major tick numeric values with text, one label per major tick
+    pub fn set_axis_labels(&self, axis: LvglChartAxis, labels: &[&str]) -> &Self {
+        let mut list = Vec::new();
+        for label in labels {
+            let text = match CString::new(*label) {
+                Err(_) => CString::new("Non UTF8 label").unwrap(),
+                Ok(value) => value,
+            };
+            list.push(text);
+        }
+        self.labels.borrow_mut()[axis.get_index()] = list;
+        unsafe {
+            cglue::lv_obj_invalidate(self.handle);
+        }
+        self
+    }
+
+    // series are referenced by creation order index
+    pub fn add_series(&self, color: LvglColor, axis: LvglChartAxis) -> &Self {
+        unsafe {
+            let serie = cglue::lv_chart_add_series(self.handle, color.handle, axis.get_raw());
+            self.series.borrow_mut().push(serie);
+        }
+        self
+    }
+
+    pub fn get_series_count(&self) -> usize {
+        self.series.borrow().len()
+    }
+
+    fn get_series(&self, index: usize) -> Option<*mut cglue::lv_chart_series_t> {
+        self.series.borrow().get(index).copied()
+    }
+
+    pub fn set_series_color(&self, index: usize, color: LvglColor) -> &Self {
+        if let Some(serie) = self.get_series(index) {
+            unsafe {
+                cglue::lv_chart_set_series_color(self.handle, serie, color.handle);
+            }
+        }
+        self
+    }
+
+    // append one point, oldest one is dropped (shift) or overwritten (circular)
+    pub fn push_value(&self, index: usize, value: i16) -> &Self {
+        if let Some(serie) = self.get_series(index) {
+            unsafe {
+                cglue::lv_chart_set_next_value(self.handle, serie, value);
+            }
+        }
+        self
+    }
+
+    // scatter charts need both coordinates
+    pub fn push_point(&self, index: usize, x_value: i16, y_value: i16) -> &Self {
+        if let Some(serie) = self.get_series(index) {
+            unsafe {
+                cglue::lv_chart_set_next_value2(self.handle, serie, x_value, y_value);
+            }
+        }
+        self
+    }
+
+    pub fn set_all(&self, index: usize, value: i16) -> &Self {
+        if let Some(serie) = self.get_series(index) {
+            unsafe {
+                cglue::lv_chart_set_all_value(self.handle, serie, value);
+            }
+        }
+        self
+    }
+
+    // replace series points in one go, chart is only refreshed once
+    pub fn set_values(&self, index: usize, values: &[i16]) -> &Self {
+        if let Some(serie) = self.get_series(index) {
+            unsafe {
+                let count = cglue::lv_chart_get_point_count(self.handle) as usize;
+                for (idx, value) in values.iter().take(count).enumerate() {
+                    cglue::lv_chart_set_value_by_id(self.handle, serie, idx as u16, *value);
+                }
+                cglue::lv_chart_refresh(self.handle);
+            }
+        }
+        self
+    }
+
+    pub fn get_pressed_point(&self) -> Option<u16> {
+        let point = unsafe { cglue::lv_chart_get_pressed_point(self.handle) };
+        if point == cglue::LV_CHART_POINT_NONE as u16 {
+            None
+        } else {
+            Some(point)
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+// container created by tabview, tileview or menu, derefs to LvglWidget to be used directly
+// as parent. Page events go to its own handler or default to the owner widget handler.
+pub struct LvglPage {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    owner: &'static Cell<Option<*mut dyn LvglHandler>>,
+    generic: OnceCell<LvglWidget>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// events are registered at creation, generic widget is built once and shared with lvgl
+impl LvglCommon for LvglPage {
+    fn get_uid(&self) -> &'static str {
+        self.uid
+    }
+    fn get_info(&self) -> &'static str {
+        self.info.get()
+    }
+    fn set_info(&self, info: &'static str) -> &Self {
+        self.info.set(info);
+        self
+    }
+    fn get_handle(&self) -> *mut cglue::_lv_obj_t {
+        self.handle
+    }
+    fn get_style(&self) -> *mut cglue::lv_style_t {
+        self.style
+    }
+    fn get_callback(&self) -> Option<*mut dyn LvglHandler> {
+        self.ctrlbox.get().or(self.owner.get())
+    }
+    fn get_generic(&'static self) -> LvglWidget {
+        LvglWidget::Page(self)
+    }
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn set_callback(&'static self, ctrlbox: *mut dyn LvglHandler) -> &Self {
+        self.ctrlbox.set(Some(ctrlbox));
+        self
+    }
+    fn finalize(&'static self) -> &'static LvglWidget {
+        self.generic.get().unwrap()
+    }
+}
+impl LvglMethod for LvglPage {}
+
+impl Deref for LvglPage {
+    type Target = LvglWidget;
+    fn deref(&self) -> &LvglWidget {
+        self.generic.get().unwrap()
+    }
+}
+
+impl LvglPage {
+    fn new(
+        uid: &'static str,
+        handle: *mut cglue::_lv_obj_t,
+        owner: &'static Cell<Option<*mut dyn LvglHandler>>,
+    ) -> &'static Self {
+        unsafe {
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let page: &'static LvglPage = Box::leak(Box::new(LvglPage {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                owner,
+                generic: OnceCell::new(),
+                ctrlbox: Cell::new(None),
+            }));
+            let generic = page.generic.get_or_init(|| LvglWidget::Page(page));
+            cglue::lv_obj_add_event_cb(
+                handle,
+                Some(lvgl_events_cb),
+                cglue::lv_event_code_t_LV_EVENT_CLICKED,
+                generic as *const _ as *mut raw::c_void,
+            );
+            page
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.get_callback() {
+            match event {
+                LvglEvent::CLICKED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglTabview {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    tabs: RefCell<Vec<&'static LvglPage>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglTabview, Tabview);
+impl LvglTabview {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        position: LvglDirection, // where tab buttons are drawn
+        tab_size: i16,
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle =
+                cglue::lv_tabview_create(parent.get_handle(), position.get_raw(), tab_size);
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglTabview {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                tabs: RefCell::new(Vec::new()),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // tab page is used directly as parent for other widgets
+    pub fn add_tab(&'static self, uid: &'static str, title: &str) -> &'static LvglPage {
+        let title = match CString::new(title) {
+            Err(_) => CString::new("Non UTF8 title").unwrap(),
+            Ok(value) => value,
+        };
+        let page = unsafe {
+            let handle = cglue::lv_tabview_add_tab(self.handle, title.as_ptr());
+            LvglPage::new(uid, handle, &self.ctrlbox)
+        };
+        self.tabs.borrow_mut().push(page);
+        page
+    }
+
+    pub fn get_tab(&self, index: usize) -> Option<&'static LvglPage> {
+        self.tabs.borrow().get(index).copied()
+    }
+
+    pub fn set_active(&self, index: u16, animated: bool) -> &Self {
+        let anim = if animated {
+            cglue::lv_anim_enable_t_LV_ANIM_ON
+        } else {
+            cglue::lv_anim_enable_t_LV_ANIM_OFF
+        };
+        unsafe {
+            cglue::lv_tabview_set_act(self.handle, index as u32, anim);
+        }
+        self
+    }
+
+    pub fn get_active(&self) -> u16 {
+        unsafe { cglue::lv_tabview_get_tab_act(self.handle) }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglTileview {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    tiles: RefCell<Vec<&'static LvglPage>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglTileview, Tileview);
+impl LvglTileview {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_tileview_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglTileview {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                tiles: RefCell::new(Vec::new()),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // directions define where user may swipe from this tile
+    pub fn add_tile(
+        &'static self,
+        uid: &'static str,
+        column: u8,
+        row: u8,
+        directions: &[LvglDirection],
+    ) -> &'static LvglPage {
+        let tile = unsafe {
+            let handle = cglue::lv_tileview_add_tile(
+                self.handle,
+                column,
+                row,
+                LvglDirection::get_mask(directions),
+            );
+            LvglPage::new(uid, handle, &self.ctrlbox)
+        };
+        self.tiles.borrow_mut().push(tile);
+        tile
+    }
+
+    pub fn set_active(&self, column: u32, row: u32, animated: bool) -> &Self {
+        let anim = if animated {
+            cglue::lv_anim_enable_t_LV_ANIM_ON
+        } else {
+            cglue::lv_anim_enable_t_LV_ANIM_OFF
+        };
+        unsafe {
+            cglue::lv_obj_set_tile_id(self.handle, column, row, anim);
+        }
+        self
+    }
+
+    pub fn get_active(&self) -> Option<&'static LvglPage> {
+        let handle = unsafe { cglue::lv_tileview_get_tile_act(self.handle) };
+        self.tiles
+            .borrow()
+            .iter()
+            .find(|tile| tile.get_handle() == handle)
+            .copied()
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglMsgbox {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    _buttons: Vec<CString>,
+    _btnmap: Vec<*const raw::c_char>,
+    closed: Cell<bool>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// lvgl object is gone, msgbox methods should not touch its handle anymore
+extern "C" fn msgbox_delete_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let msgbox = &*(cglue::lv_event_get_user_data(event) as *const LvglMsgbox);
+        msgbox.closed.set(true);
+    }
+}
+
+impl_widget_trait!(LvglMsgbox, Msgbox);
+impl LvglMsgbox {
+    // msgbox is modal: lvgl creates it on top layer with a background blocking other widgets
+    pub fn new(
+        uid: &'static str,
+        title: &str,
+        text: &str,
+        buttons: &[&str],
+        closable: bool,
+    ) -> &'static Self {
+        let title = match CString::new(title) {
+            Err(_) => CString::new("Non UTF8 title").unwrap(),
+            Ok(value) => value,
+        };
+        let text = match CString::new(text) {
+            Err(_) => CString::new("Non UTF8 text").unwrap(),
+            Ok(value) => value,
+        };
+
+        // lvgl keeps a reference on button map, it should live as long as the msgbox
+        let mut labels = Vec::new();
+        for button in buttons {
+            let label = match CString::new(*button) {
+                Err(_) => CString::new("Non UTF8 label").unwrap(),
+                Ok(value) => value,
+            };
+            labels.push(label);
+        }
+        let mut btnmap: Vec<*const raw::c_char> =
+            labels.iter().map(|label| label.as_ptr()).collect();
+        btnmap.push(b"\0".as_ptr() as *const raw::c_char);
+
+        unsafe {
+            let handle = cglue::lv_msgbox_create(
+                0 as *mut cglue::lv_obj_t,
+                title.as_ptr(),
+                text.as_ptr(),
+                btnmap.as_mut_ptr(),
+                closable,
+            );
+            cglue::lv_obj_center(handle);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = Box::leak(Box::new(LvglMsgbox {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                _buttons: labels,
+                _btnmap: btnmap,
+                closed: Cell::new(false),
+                ctrlbox: Cell::new(None),
+            }));
+
+            cglue::lv_obj_add_event_cb(
+                handle,
+                Some(msgbox_delete_cb),
+                cglue::lv_event_code_t_LV_EVENT_DELETE,
+                widget as *const _ as *mut raw::c_void,
+            );
+            widget
+        }
+    }
+
+    // closed by close() or by lvgl title close button
+    pub fn is_closed(&self) -> bool {
+        self.closed.get()
+    }
+
+    // index of the button that triggered last VALUE_CHANGED
+    pub fn get_active_button(&self) -> Option<u16> {
+        if self.closed.get() {
+            return None;
+        }
+        let index = unsafe { cglue::lv_msgbox_get_active_btn(self.handle) };
+        if index == cglue::LV_BTNMATRIX_BTN_NONE as u16 {
+            None
+        } else {
+            Some(index)
+        }
+    }
+
+    pub fn get_active_text(&self) -> Option<String> {
+        if self.closed.get() {
+            return None;
+        }
+        unsafe {
+            let text = cglue::lv_msgbox_get_active_btn_text(self.handle);
+            if text.is_null() {
+                return None;
+            }
+            match CStr::from_ptr(text).to_str() {
+                Err(_) => None,
+                Ok(value) => Some(value.to_owned()),
+            }
+        }
+    }
+
+    // deletion is deferred, close can safely be called from handler callback,
+    // msgbox is marked closed immediately so a second close does not delete it twice
+    pub fn close(&self) {
+        if self.closed.replace(true) {
+            return;
+        }
+        unsafe {
+            cglue::lv_msgbox_close_async(self.handle);
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglSpinner {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglSpinner, Spinner);
+impl LvglSpinner {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        period: u32,     // ms for one turn
+        arc_length: u32, // degree
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_spinner_create(parent.get_handle(), period, arc_length);
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglSpinner {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    pub fn set_color(&self, color: LvglColor) -> &Self {
+        unsafe {
+            cglue::lv_obj_set_style_arc_color(self.handle, color.handle, cglue::LV_PART_INDICATOR);
+        }
+        self
+    }
+
+    pub fn set_background(&self, color: LvglColor) -> &Self {
+        unsafe {
+            cglue::lv_obj_set_style_arc_color(self.handle, color.handle, cglue::LV_PART_MAIN);
+        }
+        self
+    }
+
+    pub fn set_width(&self, width: i16) -> &Self {
+        unsafe {
+            cglue::lv_obj_set_style_arc_width(self.handle, width, cglue::LV_PART_MAIN);
+            cglue::lv_obj_set_style_arc_width(self.handle, width, cglue::LV_PART_INDICATOR);
+        }
+        self
+    }
+}
+
+pub struct LvglCalendar {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    highlighted: RefCell<Vec<LvglDate>>,
+    textarea: Cell<*mut cglue::_lv_obj_t>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// popup mode: show calendar when textarea is focused, copy selected date back and hide it
+extern "C" fn calendar_popup_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let calendar = &*(cglue::lv_event_get_user_data(event) as *const LvglCalendar);
+        let target = cglue::lv_event_get_target(event);
+        let code = cglue::lv_event_get_code(event);
+        let textarea = calendar.textarea.get();
+
+        if target == textarea {
+            if code == cglue::lv_event_code_t_LV_EVENT_FOCUSED
+                || code == cglue::lv_event_code_t_LV_EVENT_CLICKED
+            {
+                cglue::lv_obj_align_to(
+                    calendar.handle,
+                    textarea,
+                    cglue::LV_ALIGN_OUT_BOTTOM_LEFT as u8,
+                    0,
+                    5,
+                );
+                cglue::lv_obj_clear_flag(calendar.handle, cglue::LV_OBJ_FLAG_HIDDEN);
+            }
+        } else if code == cglue::lv_event_code_t_LV_EVENT_VALUE_CHANGED {
+            if let Some(date) = calendar.get_pressed_date() {
+                let text = format!("{:04}-{:02}-{:02}", date.year, date.month, date.day);
+                let text = CString::new(text).unwrap();
+                cglue::lv_textarea_set_text(textarea, text.as_ptr());
+                cglue::lv_obj_add_flag(calendar.handle, cglue::LV_OBJ_FLAG_HIDDEN);
+            }
+        }
+    }
+}
+
+impl_widget_trait!(LvglCalendar, Calendar);
+impl LvglCalendar {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_calendar_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglCalendar {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                highlighted: RefCell::new(Vec::new()),
+                textarea: Cell::new(0 as *mut cglue::_lv_obj_t),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    pub fn set_today(&self, year: u16, month: u8, day: u8) -> &Self {
+        unsafe {
+            cglue::lv_calendar_set_today_date(self.handle, year as u32, month as u32, day as u32);
+        }
+        self
+    }
+
+    // use local time to set both today and showed month
+    pub fn set_today_now(&self) -> &Self {
+        if let Ok(date) = get_time("%Y %m %d") {
+            let fields: Vec<u16> = date
+                .split(' ')
+                .filter_map(|field| field.parse::<u16>().ok())
+                .collect();
+            if fields.len() == 3 {
+                self.set_today(fields[0], fields[1] as u8, fields[2] as u8);
+                self.set_showed(fields[0], fields[1] as u8);
+            }
+        }
+        self
+    }
+
+    pub fn set_showed(&self, year: u16, month: u8) -> &Self {
+        unsafe {
+            cglue::lv_calendar_set_showed_date(self.handle, year as u32, month as u32);
+        }
+        self
+    }
+
+    // lvgl keeps a reference on dates, they live within calendar widget
+    pub fn set_highlighted(&self, dates: &[LvglDate]) -> &Self {
+        let mut highlighted = self.highlighted.borrow_mut();
+        *highlighted = dates.to_vec();
+        unsafe {
+            cglue::lv_calendar_set_highlighted_dates(
+                self.handle,
+                highlighted.as_mut_ptr(),
+                highlighted.len() as u16,
+            );
+        }
+        self
+    }
+
+    pub fn set_header_arrow(&self) -> &Self {
+        unsafe {
+            cglue::lv_calendar_header_arrow_create(self.handle);
+        }
+        self
+    }
+
+    pub fn set_header_dropdown(&self) -> &Self {
+        unsafe {
+            cglue::lv_calendar_header_dropdown_create(self.handle);
+        }
+        self
+    }
+
+    // turn calendar into a compact hidden popup filling textarea with YYYY-MM-DD
+    pub fn set_textarea(&'static self, textarea: &LvglTextArea) -> &Self {
+        let ta_handle = textarea.get_handle();
+        self.textarea.set(ta_handle);
+        unsafe {
+            let layer = cglue::lv_disp_get_layer_top(cglue::lv_disp_get_default());
+            cglue::lv_obj_set_parent(self.handle, layer);
+            cglue::lv_obj_add_flag(self.handle, cglue::LV_OBJ_FLAG_HIDDEN);
+
+            let context = self as *const _ as *mut raw::c_void;
+            cglue::lv_obj_add_event_cb(
+                ta_handle,
+                Some(calendar_popup_cb),
+                cglue::lv_event_code_t_LV_EVENT_ALL,
+                context,
+            );
+            cglue::lv_obj_add_event_cb(
+                self.handle,
+                Some(calendar_popup_cb),
+                cglue::lv_event_code_t_LV_EVENT_VALUE_CHANGED,
+                context,
+            );
+        }
+        self
+    }
+
+    pub fn get_pressed_date(&self) -> Option<LvglDate> {
+        unsafe {
+            let mut date = mem::zeroed::<LvglDate>();
+            if cglue::lv_calendar_get_pressed_date(self.handle, &mut date) == cglue::LV_RES_OK as u8
+            {
+                Some(date)
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglList {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    entries: RefCell<Vec<(*mut cglue::_lv_obj_t, &'static str)>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglList, List);
+impl LvglList {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_list_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglList {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                entries: RefCell::new(Vec::new()),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // non clickable section header
+    pub fn add_text(&self, text: &str) -> &Self {
+        unsafe {
+            let text = match CString::new(text) {
+                Err(_) => CString::new("Non UTF8 text").unwrap(),
+                Ok(value) => value,
+            };
+            cglue::lv_list_add_text(self.handle, text.as_ptr());
+        }
+        self
+    }
+
+    // clicking a button reports its uid to list handler
+    pub fn add_button(
+        &'static self,
+        uid: &'static str,
+        icon: Option<&'static [u8; 4]>,
+        text: &str,
+    ) -> &Self {
+        let text = match CString::new(text) {
+            Err(_) => CString::new("Non UTF8 text").unwrap(),
+            Ok(value) => value,
+        };
+        let icon = match icon {
+            None => 0 as *const raw::c_void,
+            Some(value) => value as *const _ as *const raw::c_void,
+        };
+        unsafe {
+            let button = cglue::lv_list_add_btn(self.handle, icon, text.as_ptr());
+            let context = Box::leak(Box::new(LvglWidget::List(self)));
+            cglue::lv_obj_add_event_cb(
+                button,
+                Some(lvgl_events_cb),
+                cglue::lv_event_code_t_LV_EVENT_CLICKED,
+                context as *const _ as *mut raw::c_void,
+            );
+            self.entries.borrow_mut().push((button, uid));
+        }
+        self
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, target: *mut cglue::_lv_obj_t, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::CLICKED => {}
+                _ => return, // ignore other events
+            }
+            let uid = match self.entries.borrow().iter().find(|entry| entry.0 == target) {
+                Some(entry) => entry.1,
+                None => return, // event not from an entry
+            };
+            unsafe { (*ctrlbox).callback(widget, uid, event) };
+        }
+    }
+}
+
+pub struct LvglMenu {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    entries: RefCell<Vec<(*mut cglue::_lv_obj_t, &'static str)>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglMenu, Menu);
+impl LvglMenu {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_menu_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglMenu {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                entries: RefCell::new(Vec::new()),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // pages are not displayed until used as root, sidebar or entry submenu
+    pub fn add_page(&'static self, uid: &'static str, title: Option<&str>) -> &'static LvglPage {
+        let title = match title {
+            None => None,
+            Some(text) => match CString::new(text) {
+                Err(_) => Some(CString::new("Non UTF8 title").unwrap()),
+                Ok(value) => Some(value),
+            },
+        };
+        let title_ptr = match &title {
+            None => 0 as *mut raw::c_char,
+            Some(value) => value.as_ptr() as *mut raw::c_char,
+        };
+        unsafe {
+            let handle = cglue::lv_menu_page_create(self.handle, title_ptr);
+            LvglPage::new(uid, handle, &self.ctrlbox)
+        }
+    }
+
+    // sections group entries with a common background within a page
+    pub fn add_section(&'static self, uid: &'static str, page: &LvglPage) -> &'static LvglPage {
+        unsafe {
+            let handle = cglue::lv_menu_section_create(page.get_handle());
+            LvglPage::new(uid, handle, &self.ctrlbox)
+        }
+    }
+
+    pub fn add_separator(&self, page: &LvglPage) -> &Self {
+        unsafe {
+            cglue::lv_menu_separator_create(page.get_handle());
+        }
+        self
+    }
+
+    // entry parent is a page or a section, clicking it loads submenu when defined
+    pub fn add_entry(
+        &'static self,
+        parent: &LvglPage,
+        uid: &'static str,
+        icon: Option<&'static [u8; 4]>,
+        text: &str,
+        submenu: Option<&LvglPage>,
+    ) -> &Self {
+        let text = match CString::new(text) {
+            Err(_) => CString::new("Non UTF8 text").unwrap(),
+            Ok(value) => value,
+        };
+        unsafe {
+            let entry = cglue::lv_menu_cont_create(parent.get_handle());
+            if let Some(icon) = icon {
+                let image = cglue::lv_img_create(entry);
+                cglue::lv_img_set_src(image, icon as *const _ as *const raw::c_void);
+            }
+            let label = cglue::lv_label_create(entry);
+            cglue::lv_label_set_text(label, text.as_ptr());
+
+            match submenu {
+                Some(page) => {
+                    cglue::lv_menu_set_load_page_event(self.handle, entry, page.get_handle())
+                }
+                None => cglue::lv_obj_add_flag(entry, cglue::LV_OBJ_FLAG_CLICKABLE),
+            }
+
+            let context = Box::leak(Box::new(LvglWidget::Menu(self)));
+            cglue::lv_obj_add_event_cb(
+                entry,
+                Some(lvgl_events_cb),
+                cglue::lv_event_code_t_LV_EVENT_CLICKED,
+                context as *const _ as *mut raw::c_void,
+            );
+            self.entries.borrow_mut().push((entry, uid));
+        }
+        self
+    }
+
+    pub fn set_root(&self, page: &LvglPage) -> &Self {
+        unsafe {
+            cglue::lv_menu_set_page(self.handle, page.get_handle());
+        }
+        self
+    }
+
+    // sidebar mode: page stays on the left while submenus are displayed on the right
+    pub fn set_sidebar(&self, page: &LvglPage) -> &Self {
+        unsafe {
+            cglue::lv_menu_set_sidebar_page(self.handle, page.get_handle());
+        }
+        self
+    }
+
+    pub fn set_back_button(&self, enable: bool) -> &Self {
+        let mode = if enable {
+            cglue::LV_MENU_ROOT_BACK_BTN_ENABLED
+        } else {
+            cglue::LV_MENU_ROOT_BACK_BTN_DISABLED
+        };
+        unsafe {
+            cglue::lv_menu_set_mode_root_back_btn(self.handle, mode as u8);
+        }
+        self
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, target: *mut cglue::_lv_obj_t, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::CLICKED => {}
+                _ => return, // ignore other events
+            }
+            let uid = match self.entries.borrow().iter().find(|entry| entry.0 == target) {
+                Some(entry) => entry.1,
+                None => return, // event not from an entry
+            };
+            unsafe { (*ctrlbox).callback(widget, uid, event) };
+        }
+    }
+}
+
+// lvgl caps digits count and ignores separator when not within digits
+fn spinbox_format(digits: u8, separator: u8) -> (u8, u8, u8) {
+    let digits = digits.min(cglue::LV_SPINBOX_MAX_DIGIT_COUNT as u8);
+    if separator == 0 || separator >= digits {
+        (digits, 0, 0)
+    } else {
+        (digits, separator, digits - separator)
+    }
+}
+
+pub struct LvglSpinbox {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    decimals: Cell<u8>,
+    buttons: Cell<Option<(&'static LvglButton, &'static LvglButton)>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+
+// increment/decrement buttons use standard button handler to drive their spinbox
+struct SpinboxStepCtx {
+    spinbox: &'static LvglSpinbox,
+    increment: bool,
+}
+impl LvglHandler for SpinboxStepCtx {
+    fn callback(&self, _widget: &LvglWidget, _uid: &'static str, _event: &LvglEvent) {
+        if self.increment {
+            self.spinbox.increment();
+        } else {
+            self.spinbox.decrement();
+        }
+    }
+}
+
+// keep buttons stuck on spinbox sides with the same height
+extern "C" fn spinbox_resize_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let spinbox = &*(cglue::lv_event_get_user_data(event) as *const LvglSpinbox);
+        spinbox.align_buttons();
+    }
+}
+
+impl_widget_trait!(LvglSpinbox, Spinbox);
+impl LvglSpinbox {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        digits: u8,
+        separator: u8, // number of integer digits, 0 for no decimal separator
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_spinbox_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+            let (digits, separator, decimals) = spinbox_format(digits, separator);
+            cglue::lv_spinbox_set_digit_format(handle, digits, separator);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglSpinbox {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                decimals: Cell::new(decimals),
+                buttons: Cell::new(None),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    pub fn set_digits(&self, digits: u8, separator: u8) -> &Self {
+        let (digits, separator, decimals) = spinbox_format(digits, separator);
+        self.decimals.set(decimals);
+        unsafe {
+            cglue::lv_spinbox_set_digit_format(self.handle, digits, separator);
+        }
+        self
+    }
+
+    pub fn set_range(&self, min: i32, max: i32) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_set_range(self.handle, min, max);
+        }
+        self
+    }
+
+    // step is expressed in raw integer unit (step 10 on 2 decimals is 0.10)
+    pub fn set_step(&self, step: u32) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_set_step(self.handle, step);
+        }
+        self
+    }
+
+    pub fn set_rollover(&self, rollover: bool) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_set_rollover(self.handle, rollover);
+        }
+        self
+    }
+
+    pub fn set_value(&self, value: i32) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_set_value(self.handle, value);
+        }
+        self
+    }
+
+    pub fn set_fixed(&self, value: f64) -> &Self {
+        let scale = 10_i32.pow(self.decimals.get() as u32) as f64;
+        self.set_value((value * scale).round() as i32)
+    }
+
+    pub fn get_value(&self) -> i32 {
+        unsafe { cglue::lv_spinbox_get_value(self.handle) }
+    }
+
+    // value with decimal separator applied
+    pub fn get_fixed(&self) -> f64 {
+        let scale = 10_i32.pow(self.decimals.get() as u32) as f64;
+        self.get_value() as f64 / scale
+    }
+
+    pub fn increment(&self) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_increment(self.handle);
+        }
+        self
+    }
+
+    pub fn decrement(&self) -> &Self {
+        unsafe {
+            cglue::lv_spinbox_decrement(self.handle);
+        }
+        self
+    }
+
+    // add -/+ buttons on spinbox left/right sides
+    pub fn set_buttons(&'static self, parent: &LvglWidget, font: &LvglFont) -> &Self {
+        if self.buttons.get().is_some() {
+            return self;
+        }
+        let uid = Box::leak(format!("{}-Minus", self.uid).into_boxed_str());
+        let minus = LvglButton::new(parent, uid, font, 0, 0);
+        minus.set_value(std::str::from_utf8(&LvglIcon::MINUS[0..3]).unwrap());
+        minus.set_callback(Box::leak(Box::new(SpinboxStepCtx {
+            spinbox: self,
+            increment: false,
+        })));
+
+        let uid = Box::leak(format!("{}-Plus", self.uid).into_boxed_str());
+        let plus = LvglButton::new(parent, uid, font, 0, 0);
+        plus.set_value(std::str::from_utf8(&LvglIcon::PLUS[0..3]).unwrap());
+        plus.set_callback(Box::leak(Box::new(SpinboxStepCtx {
+            spinbox: self,
+            increment: true,
+        })));
+
+        self.buttons.set(Some((minus, plus)));
+        self.align_buttons();
+        unsafe {
+            cglue::lv_obj_add_event_cb(
+                self.handle,
+                Some(spinbox_resize_cb),
+                cglue::lv_event_code_t_LV_EVENT_SIZE_CHANGED,
+                self as *const _ as *mut raw::c_void,
+            );
+        }
+        self
+    }
+
+    fn align_buttons(&self) {
+        if let Some((minus, plus)) = self.buttons.get() {
+            unsafe {
+                cglue::lv_obj_update_layout(self.handle);
+                let height = cglue::lv_obj_get_height(self.handle);
+                for (button, align, gap) in [
+                    (minus, cglue::LV_ALIGN_OUT_LEFT_MID, -5),
+                    (plus, cglue::LV_ALIGN_OUT_RIGHT_MID, 5),
+                ] {
+                    cglue::lv_obj_set_size(button.get_handle(), height, height);
+                    cglue::lv_obj_align_to(button.get_handle(), self.handle, align as u8, gap, 0);
+                }
+            }
+        }
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::VALUE_CHANGED => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglImgButton {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    label: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglImgButton, ImgButton);
+impl LvglImgButton {
+    pub fn new(
+        parent: &LvglWidget,
+        uid: &'static str,
+        font: &LvglFont,
+        x_ofs: i16,
+        y_ofs: i16,
+    ) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_imgbtn_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_style_set_text_font(style, font as *const _ as *const cglue::lv_font_t);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            // create a label as children on button
+            let label = cglue::lv_label_create(handle);
+            cglue::lv_obj_align(label, cglue::LV_ALIGN_CENTER as u8, 0, 0);
+
+            let widget = LvglImgButton {
+                uid,
+                info: Cell::new(""),
+                handle,
+                label,
+                style,
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // mid image is tiled to fill button width, left/right caps are optional
+    pub fn set_src(
+        &self,
+        state: LvglImgButtonState,
+        left: Option<&'static LvglImgDsc>,
+        mid: &'static LvglImgDsc,
+        right: Option<&'static LvglImgDsc>,
+    ) -> &Self {
+        let left = match left {
+            None => 0 as *const raw::c_void,
+            Some(image) => image as *const _ as *const raw::c_void,
+        };
+        let right = match right {
+            None => 0 as *const raw::c_void,
+            Some(image) => image as *const _ as *const raw::c_void,
+        };
+        unsafe {
+            cglue::lv_imgbtn_set_src(
+                self.handle,
+                state.get_raw(),
+                left,
+                mid as *const _ as *const raw::c_void,
+                right,
+            );
+        }
+        self
+    }
+
+    // bundled button_left/mid/right assets on every state, pressed/checked are recolored
+    pub fn set_default_skin(&self) -> &Self {
+        for state in LvglImgButtonState::all() {
+            self.set_src(
+                state,
+                Some(LvglMkImage::button_left()),
+                LvglMkImage::button_mid(),
+                Some(LvglMkImage::button_right()),
+            );
+        }
+        unsafe {
+            let black = cglue::lv_color_mk(0, 0, 0);
+            cglue::lv_obj_set_style_img_recolor(self.handle, black, cglue::LV_STATE_PRESSED);
+            cglue::lv_obj_set_style_img_recolor_opa(
+                self.handle,
+                cglue::LV_OPA_30 as u8,
+                cglue::LV_STATE_PRESSED,
+            );
+            let checked = cglue::lv_palette_main(LvglPalette::BLUE);
+            cglue::lv_obj_set_style_img_recolor(self.handle, checked, cglue::LV_STATE_CHECKED);
+            cglue::lv_obj_set_style_img_recolor_opa(
+                self.handle,
+                cglue::LV_OPA_50 as u8,
+                cglue::LV_STATE_CHECKED,
+            );
+        }
+        self
+    }
+
+    pub fn set_value(&self, label: &str) -> &Self {
+        unsafe {
+            let text = match CString::new(label) {
+                Err(_) => CString::new("Non UTF8 label").unwrap(),
+                Ok(value) => value,
+            };
+            cglue::lv_label_set_text(self.label, text.as_ptr());
+        }
+        self
+    }
+
+    // toggle mode: each click switches checked state and sends VALUE_CHANGED
+    pub fn set_checkable(&self, checkable: bool) -> &Self {
+        unsafe {
+            if checkable {
+                cglue::lv_obj_add_flag(self.handle, cglue::LV_OBJ_FLAG_CHECKABLE);
+            } else {
+                cglue::lv_obj_clear_flag(self.handle, cglue::LV_OBJ_FLAG_CHECKABLE);
+            }
+        }
+        self
+    }
+
+    pub fn set_checked(&self, checked: bool) -> &Self {
+        unsafe {
+            if checked {
+                cglue::lv_obj_add_state(self.handle, cglue::LV_STATE_CHECKED as u16);
+            } else {
+                cglue::lv_obj_clear_state(self.handle, cglue::LV_STATE_CHECKED as u16);
+            }
+        }
+        self
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.get_states().check(LvglState::CHECKED)
+    }
+
+    // matches the event forwarded by callback
+    pub fn get_action(&self) -> &'static str {
+        if unsafe { cglue::lv_obj_has_flag(self.handle, cglue::LV_OBJ_FLAG_CHECKABLE) } {
+            &"['ON','OFF']"
+        } else {
+            &"['CLICKED']"
+        }
+    }
+
+    // checkable button reports VALUE_CHANGED, plain button reports CLICKED
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            let checkable =
+                unsafe { cglue::lv_obj_has_flag(self.handle, cglue::LV_OBJ_FLAG_CHECKABLE) };
+            match event {
+                LvglEvent::CLICKED if !checkable => {}
+                LvglEvent::VALUE_CHANGED if checkable => {}
+                _ => return, // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
+
+pub struct LvglAnimImage {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    frames: RefCell<Vec<*mut cglue::lv_img_dsc_t>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglAnimImage, AnimImage);
+impl LvglAnimImage {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_animimg_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglAnimImage {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                frames: RefCell::new(Vec::new()),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // frames are static images or icons, lvgl keeps a reference on frame list (max 255 frames)
+    pub fn set_frames<T: Copy>(&self, frames: &[T]) -> Result<&Self, LvglError>
+    where
+        LvglPixmap: ImgToVoid<T>,
+    {
+        if frames.len() > u8::MAX as usize {
+            return Err(LvglError::Unsupported(format!(
+                "animimg frames count:{} max:{}",
+                frames.len(),
+                u8::MAX
+            )));
+        }
+        let mut list = self.frames.borrow_mut();
+        *list = frames
+            .iter()
+            .map(|frame| LvglPixmap::get_ref(*frame) as *mut cglue::lv_img_dsc_t)
+            .collect();
+        unsafe {
+            cglue::lv_animimg_set_src(self.handle, list.as_mut_ptr(), list.len() as u8);
+        }
+        Ok(self)
+    }
+
+    // duration of a full frame cycle in ms
+    pub fn set_duration(&self, duration: u32) -> &Self {
+        unsafe {
+            cglue::lv_animimg_set_duration(self.handle, duration);
+        }
+        self
+    }
+
+    // None loops forever
+    pub fn set_repeat(&self, count: Option<u16>) -> &Self {
+        let count = match count {
+            None => cglue::LV_ANIM_REPEAT_INFINITE as u16,
+            Some(value) => value,
+        };
+        unsafe {
+            cglue::lv_animimg_set_repeat_count(self.handle, count);
+        }
+        self
+    }
+
+    pub fn start(&self) -> &Self {
+        unsafe {
+            cglue::lv_animimg_start(self.handle);
+        }
+        self
+    }
+
+    // animimg has no stop api, only remove its frame animation (exec_cb is private to lvgl)
+    pub fn stop(&self) -> &Self {
+        unsafe {
+            let animimg = self.handle as *mut cglue::lv_animimg_t;
+            cglue::lv_anim_del(self.handle as *mut raw::c_void, (*animimg).anim.exec_cb);
+        }
+        self
+    }
+}
+
+pub struct LvglGif {
+    uid: &'static str,
+    info: Cell<&'static str>,
+    handle: *mut cglue::_lv_obj_t,
+    style: *mut cglue::lv_style_t,
+    source: RefCell<Option<Box<LvglImgDsc>>>,
+    ctrlbox: Cell<Option<*mut dyn LvglHandler>>,
+}
+impl_widget_trait!(LvglGif, Gif);
+impl LvglGif {
+    pub fn new(parent: &LvglWidget, uid: &'static str, x_ofs: i16, y_ofs: i16) -> &'static Self {
+        unsafe {
+            let handle = cglue::lv_gif_create(parent.get_handle());
+            cglue::lv_obj_align(handle, cglue::LV_ALIGN_TOP_LEFT as u8, x_ofs, y_ofs);
+
+            let style = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(style);
+            cglue::lv_obj_add_style(handle, style, 0);
+
+            let widget = LvglGif {
+                uid,
+                info: Cell::new(""),
+                handle,
+                style,
+                source: RefCell::new(None),
+                ctrlbox: Cell::new(None),
+            };
+            Box::leak(Box::new(widget))
+        }
+    }
+
+    // gif is decoded while playing, data should live as long as widget (typically include_bytes!)
+    pub fn set_data(&self, data: &'static [u8]) -> &Self {
+        unsafe {
+            let mut dsc = Box::new(mem::zeroed::<LvglImgDsc>());
+            dsc.header.set_cf(cglue::LV_IMG_CF_RAW);
+            dsc.data_size = data.len() as u32;
+            dsc.data = data.as_ptr();
+            cglue::lv_gif_set_src(self.handle, &*dsc as *const _ as *const raw::c_void);
+            *self.source.borrow_mut() = Some(dsc);
+        }
+        self
+    }
+
+    pub fn set_path(&self, path: &str) -> &Self {
+        let filepath = match CString::new(lvgl_path(path)) {
+            Err(_) => CString::new("Non UTF8 path").unwrap(),
+            Ok(value) => value,
+        };
+        unsafe {
+            cglue::lv_gif_set_src(self.handle, filepath.as_ptr() as *const raw::c_void);
+        }
+        self
+    }
+
+    pub fn restart(&self) -> &Self {
+        unsafe {
+            cglue::lv_gif_restart(self.handle);
+        }
+        self
+    }
+
+    // frames are pushed by an lvgl timer owned by gif object
+    fn get_timer(&self) -> *mut cglue::lv_timer_t {
+        unsafe { (*(self.handle as *mut cglue::lv_gif_t)).timer }
+    }
+
+    pub fn pause(&self) -> &Self {
+        let timer = self.get_timer();
+        if !timer.is_null() {
+            unsafe { cglue::lv_timer_pause(timer) };
+        }
+        self
+    }
+
+    pub fn resume(&self) -> &Self {
+        let timer = self.get_timer();
+        if !timer.is_null() {
+            unsafe { cglue::lv_timer_resume(timer) };
+        }
+        self
+    }
+
+    pub fn callback(&self, widget: &LvglWidget, event: &LvglEvent) {
+        if let Some(ctrlbox) = self.ctrlbox.get() {
+            match event {
+                LvglEvent::READY => {} // loop finished
+                _ => return,           // ignore other events
+            }
+            unsafe { (*ctrlbox).callback(widget, self.uid, event) };
+        }
+    }
+}
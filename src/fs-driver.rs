@@ -0,0 +1,342 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::fs;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::mem;
+use std::os::raw;
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// drive letter prepended to widget paths without an explicit 'X:' prefix
+static DEFAULT_LETTER: AtomicU8 = AtomicU8::new(b'L');
+
+// translate a user path into lvgl 'X:/path' pattern
+pub(crate) fn lvgl_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    if bytes.len() >= 2 && bytes[0].is_ascii_uppercase() && bytes[1] == b':' {
+        path.to_string()
+    } else {
+        format!(
+            "{}:{}",
+            DEFAULT_LETTER.load(Ordering::Relaxed) as char,
+            path
+        )
+    }
+}
+
+fn io_to_res(error: &io::Error) -> cglue::lv_fs_res_t {
+    let res = match error.kind() {
+        io::ErrorKind::NotFound => cglue::LV_FS_RES_NOT_EX,
+        io::ErrorKind::PermissionDenied => cglue::LV_FS_RES_DENIED,
+        io::ErrorKind::InvalidInput => cglue::LV_FS_RES_INV_PARAM,
+        io::ErrorKind::OutOfMemory => cglue::LV_FS_RES_OUT_OF_MEM,
+        io::ErrorKind::TimedOut => cglue::LV_FS_RES_TOUT,
+        io::ErrorKind::UnexpectedEof | io::ErrorKind::Interrupted => cglue::LV_FS_RES_HW_ERR,
+        _ => cglue::LV_FS_RES_UNKNOWN,
+    };
+    res as cglue::lv_fs_res_t
+}
+
+pub struct LvglFsDriver {
+    letter: char,
+    root: PathBuf,
+    error: RefCell<Option<LvglError>>,
+    driver: *mut cglue::lv_fs_drv_t,
+}
+
+impl LvglFsDriver {
+    // expose 'root' directory to lvgl as 'letter:/...' (images, gif, binary fonts)
+    pub fn register(letter: char, root: &str) -> Result<&'static Self, LvglError> {
+        if !letter.is_ascii_uppercase() {
+            return Err(LvglError::Filesystem(format!(
+                "invalid drive letter:{}",
+                letter
+            )));
+        }
+
+        let root = match fs::canonicalize(root) {
+            Ok(value) if value.is_dir() => value,
+            _ => return Err(LvglError::InvalidPath(root.to_string())),
+        };
+
+        unsafe {
+            if !cglue::lv_fs_get_drv(letter as raw::c_char).is_null() {
+                return Err(LvglError::Filesystem(format!(
+                    "drive letter:{} already registered",
+                    letter
+                )));
+            }
+
+            let driver = Box::leak(Box::new(mem::zeroed::<cglue::lv_fs_drv_t>()));
+            cglue::lv_fs_drv_init(driver);
+
+            let fs_drv = Box::leak(Box::new(LvglFsDriver {
+                letter,
+                root,
+                error: RefCell::new(None),
+                driver,
+            }));
+
+            driver.letter = letter as raw::c_char;
+            driver.ready_cb = Some(fs_ready_cb);
+            driver.open_cb = Some(fs_open_cb);
+            driver.close_cb = Some(fs_close_cb);
+            driver.read_cb = Some(fs_read_cb);
+            driver.write_cb = Some(fs_write_cb);
+            driver.seek_cb = Some(fs_seek_cb);
+            driver.tell_cb = Some(fs_tell_cb);
+            driver.dir_open_cb = Some(fs_dir_open_cb);
+            driver.dir_read_cb = Some(fs_dir_read_cb);
+            driver.dir_close_cb = Some(fs_dir_close_cb);
+            driver.user_data = fs_drv as *const _ as *mut raw::c_void;
+            cglue::lv_fs_drv_register(driver);
+
+            Ok(fs_drv)
+        }
+    }
+
+    // paths without drive letter use this driver
+    pub fn set_default(&self) -> &Self {
+        DEFAULT_LETTER.store(self.letter as u8, Ordering::Relaxed);
+        self
+    }
+
+    pub fn get_letter(&self) -> char {
+        self.letter
+    }
+
+    pub fn get_root(&self) -> &Path {
+        self.root.as_path()
+    }
+
+    // last io error reported to lvgl, cleared on read
+    pub fn take_error(&self) -> Option<LvglError> {
+        self.error.borrow_mut().take()
+    }
+
+    pub fn get_handle(&self) -> *mut cglue::lv_fs_drv_t {
+        self.driver
+    }
+
+    // lvgl paths are relative to driver root, refuse '..' to stay inside it
+    fn resolve(&self, path: *const raw::c_char) -> Result<PathBuf, io::Error> {
+        let path = unsafe { CStr::from_ptr(path) }
+            .to_str()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "non UTF8 path"))?;
+
+        let mut fullpath = self.root.clone();
+        for component in Path::new(path).components() {
+            match component {
+                Component::Normal(value) => fullpath.push(value),
+                Component::RootDir | Component::CurDir => {}
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::PermissionDenied,
+                        format!("path:{} escapes root", path),
+                    ))
+                }
+            }
+        }
+        Ok(fullpath)
+    }
+
+    fn set_error(&self, error: &io::Error) -> cglue::lv_fs_res_t {
+        *self.error.borrow_mut() = Some(LvglError::Filesystem(format!(
+            "drive:{} {}",
+            self.letter, error
+        )));
+        io_to_res(error)
+    }
+
+    fn from_drv(drv: *mut cglue::lv_fs_drv_t) -> &'static Self {
+        unsafe { &*((*drv).user_data as *const LvglFsDriver) }
+    }
+}
+
+unsafe extern "C" fn fs_ready_cb(_drv: *mut cglue::lv_fs_drv_t) -> bool {
+    true
+}
+
+unsafe extern "C" fn fs_open_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    path: *const raw::c_char,
+    mode: cglue::lv_fs_mode_t,
+) -> *mut raw::c_void {
+    let fs_drv = LvglFsDriver::from_drv(drv);
+    let write = mode & cglue::lv_fs_mode_t_LV_FS_MODE_WR != 0;
+    let read = mode & cglue::lv_fs_mode_t_LV_FS_MODE_RD != 0;
+
+    let file = fs_drv.resolve(path).and_then(|fullpath| {
+        fs::OpenOptions::new()
+            .read(read)
+            .write(write)
+            .create(write)
+            // like lvgl stdio driver, write only replaces content while read/write updates it
+            .truncate(write && !read)
+            .open(fullpath)
+    });
+
+    match file {
+        Ok(file) => Box::into_raw(Box::new(file)) as *mut raw::c_void,
+        Err(error) => {
+            fs_drv.set_error(&error);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+unsafe extern "C" fn fs_close_cb(
+    _drv: *mut cglue::lv_fs_drv_t,
+    file_p: *mut raw::c_void,
+) -> cglue::lv_fs_res_t {
+    drop(Box::from_raw(file_p as *mut fs::File));
+    cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+}
+
+unsafe extern "C" fn fs_read_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    file_p: *mut raw::c_void,
+    buf: *mut raw::c_void,
+    btr: u32,
+    br: *mut u32,
+) -> cglue::lv_fs_res_t {
+    let file = &mut *(file_p as *mut fs::File);
+    let buffer = std::slice::from_raw_parts_mut(buf as *mut u8, btr as usize);
+
+    // lvgl expects a short count only at end of file
+    let mut count = 0;
+    while count < buffer.len() {
+        match file.read(&mut buffer[count..]) {
+            Ok(0) => break,
+            Ok(len) => count += len,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => {
+                *br = count as u32;
+                return LvglFsDriver::from_drv(drv).set_error(&error);
+            }
+        }
+    }
+    *br = count as u32;
+    cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+}
+
+unsafe extern "C" fn fs_write_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    file_p: *mut raw::c_void,
+    buf: *const raw::c_void,
+    btw: u32,
+    bw: *mut u32,
+) -> cglue::lv_fs_res_t {
+    let file = &mut *(file_p as *mut fs::File);
+    let buffer = std::slice::from_raw_parts(buf as *const u8, btw as usize);
+    match file.write_all(buffer) {
+        Ok(()) => {
+            *bw = btw;
+            cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+        }
+        Err(error) => {
+            *bw = 0;
+            LvglFsDriver::from_drv(drv).set_error(&error)
+        }
+    }
+}
+
+unsafe extern "C" fn fs_seek_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    file_p: *mut raw::c_void,
+    pos: u32,
+    whence: cglue::lv_fs_whence_t,
+) -> cglue::lv_fs_res_t {
+    let file = &mut *(file_p as *mut fs::File);
+    let seek = match whence {
+        cglue::lv_fs_whence_t_LV_FS_SEEK_CUR => SeekFrom::Current(pos as i64),
+        cglue::lv_fs_whence_t_LV_FS_SEEK_END => SeekFrom::End(pos as i64),
+        _ => SeekFrom::Start(pos as u64),
+    };
+    match file.seek(seek) {
+        Ok(_) => cglue::LV_FS_RES_OK as cglue::lv_fs_res_t,
+        Err(error) => LvglFsDriver::from_drv(drv).set_error(&error),
+    }
+}
+
+unsafe extern "C" fn fs_tell_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    file_p: *mut raw::c_void,
+    pos_p: *mut u32,
+) -> cglue::lv_fs_res_t {
+    let file = &mut *(file_p as *mut fs::File);
+    match file.stream_position() {
+        Ok(pos) => {
+            *pos_p = pos as u32;
+            cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+        }
+        Err(error) => LvglFsDriver::from_drv(drv).set_error(&error),
+    }
+}
+
+unsafe extern "C" fn fs_dir_open_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    path: *const raw::c_char,
+) -> *mut raw::c_void {
+    let fs_drv = LvglFsDriver::from_drv(drv);
+    match fs_drv.resolve(path).and_then(fs::read_dir) {
+        Ok(dir) => Box::into_raw(Box::new(dir)) as *mut raw::c_void,
+        Err(error) => {
+            fs_drv.set_error(&error);
+            std::ptr::null_mut()
+        }
+    }
+}
+
+// lvgl provides a 256 bytes buffer, directories are prefixed with '/', empty name ends listing
+unsafe extern "C" fn fs_dir_read_cb(
+    drv: *mut cglue::lv_fs_drv_t,
+    rddir_p: *mut raw::c_void,
+    fname: *mut raw::c_char,
+) -> cglue::lv_fs_res_t {
+    let dir = &mut *(rddir_p as *mut fs::ReadDir);
+    let name = match dir.next() {
+        None => String::new(),
+        Some(Err(error)) => {
+            *fname = 0;
+            return LvglFsDriver::from_drv(drv).set_error(&error);
+        }
+        Some(Ok(entry)) => {
+            let name = entry.file_name().to_string_lossy().to_string();
+            match entry.file_type() {
+                Ok(ftype) if ftype.is_dir() => format!("/{}", name),
+                _ => name,
+            }
+        }
+    };
+
+    let len = name.len().min(255);
+    std::ptr::copy_nonoverlapping(name.as_ptr(), fname as *mut u8, len);
+    *fname.add(len) = 0;
+    cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+}
+
+unsafe extern "C" fn fs_dir_close_cb(
+    _drv: *mut cglue::lv_fs_drv_t,
+    rddir_p: *mut raw::c_void,
+) -> cglue::lv_fs_res_t {
+    drop(Box::from_raw(rddir_p as *mut fs::ReadDir));
+    cglue::LV_FS_RES_OK as cglue::lv_fs_res_t
+}
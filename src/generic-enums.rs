@@ -16,10 +16,13 @@
  */
 use crate::prelude::*;
 use std::any::Any;
+use std::fmt;
+use std::ops;
 
 // exported cglue types
 pub type LvglPoint = cglue::lv_point_t;
 pub type LvglImgDsc = cglue::lv_img_dsc_t;
+pub type LvglDate = cglue::lv_calendar_date_t;
 
 // use only for test
 #[allow(dead_code)]
@@ -40,6 +43,21 @@ pub enum LvglWidget {
     Bar(&'static LvglBar),
     Qrcode(&'static LvglQrcode),
     Area(&'static LvglArea),
+    Chart(&'static LvglChart),
+    Table(&'static LvglTable),
+    Tabview(&'static LvglTabview),
+    Page(&'static LvglPage),
+    Tileview(&'static LvglTileview),
+    Msgbox(&'static LvglMsgbox),
+    Spinner(&'static LvglSpinner),
+    Calendar(&'static LvglCalendar),
+    List(&'static LvglList),
+    Menu(&'static LvglMenu),
+    Spinbox(&'static LvglSpinbox),
+    Canvas(&'static LvglCanvas),
+    ImgButton(&'static LvglImgButton),
+    AnimImage(&'static LvglAnimImage),
+    Gif(&'static LvglGif),
     Display(),
 }
 
@@ -49,11 +67,25 @@ pub trait LvglHandler {
 
 // has we share C widget callback, we have to retrieve initial object for callback
 impl LvglWidget {
-    pub(crate) fn callback(&self, event: &LvglEvent) {
+    // target is the lvgl object that received the event, it differs from widget handle for
+    // widgets registering their sub-objects (list buttons, menu entries, ...)
+    pub(crate) fn callback(&self, target: *mut cglue::_lv_obj_t, event: &LvglEvent) {
         match self {
             LvglWidget::Button(this) => this.callback(self, event),
             LvglWidget::PixButton(this) => this.callback(self, event),
             LvglWidget::Switch(this) => this.callback(self, event),
+            LvglWidget::Chart(this) => this.callback(self, event),
+            LvglWidget::Table(this) => this.callback(self, event),
+            LvglWidget::Tabview(this) => this.callback(self, event),
+            LvglWidget::Page(this) => this.callback(self, event),
+            LvglWidget::Tileview(this) => this.callback(self, event),
+            LvglWidget::Msgbox(this) => this.callback(self, event),
+            LvglWidget::Calendar(this) => this.callback(self, event),
+            LvglWidget::List(this) => this.callback(self, target, event),
+            LvglWidget::Menu(this) => this.callback(self, target, event),
+            LvglWidget::Spinbox(this) => this.callback(self, event),
+            LvglWidget::ImgButton(this) => this.callback(self, event),
+            LvglWidget::Gif(this) => this.callback(self, event),
             _ => {}
         }
     }
@@ -68,6 +100,42 @@ impl LvglWidget {
             LvglWidget::Switch(this) => {
                 this.set_callback(ctrlbox);
             }
+            LvglWidget::Chart(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Table(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Tabview(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Page(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Tileview(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Msgbox(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Calendar(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::List(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Menu(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Spinbox(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::ImgButton(this) => {
+                this.set_callback(ctrlbox);
+            }
+            LvglWidget::Gif(this) => {
+                this.set_callback(ctrlbox);
+            }
             _ => {}
         }
     }
@@ -88,6 +156,21 @@ impl LvglWidget {
             LvglWidget::Qrcode(this) => this.get_handle(),
             LvglWidget::PixButton(this) => this.get_handle(),
             LvglWidget::Area(this) => this.get_handle(),
+            LvglWidget::Chart(this) => this.get_handle(),
+            LvglWidget::Table(this) => this.get_handle(),
+            LvglWidget::Tabview(this) => this.get_handle(),
+            LvglWidget::Page(this) => this.get_handle(),
+            LvglWidget::Tileview(this) => this.get_handle(),
+            LvglWidget::Msgbox(this) => this.get_handle(),
+            LvglWidget::Spinner(this) => this.get_handle(),
+            LvglWidget::Calendar(this) => this.get_handle(),
+            LvglWidget::List(this) => this.get_handle(),
+            LvglWidget::Menu(this) => this.get_handle(),
+            LvglWidget::Spinbox(this) => this.get_handle(),
+            LvglWidget::Canvas(this) => this.get_handle(),
+            LvglWidget::ImgButton(this) => this.get_handle(),
+            LvglWidget::AnimImage(this) => this.get_handle(),
+            LvglWidget::Gif(this) => this.get_handle(),
 
             LvglWidget::Display() => unsafe { cglue::lv_scr_action() },
         }
@@ -109,6 +192,21 @@ impl LvglWidget {
             LvglWidget::Qrcode(this) => this.as_any(),
             LvglWidget::PixButton(this) => this.as_any(),
             LvglWidget::Area(this) => this.as_any(),
+            LvglWidget::Chart(this) => this.as_any(),
+            LvglWidget::Table(this) => this.as_any(),
+            LvglWidget::Tabview(this) => this.as_any(),
+            LvglWidget::Page(this) => this.as_any(),
+            LvglWidget::Tileview(this) => this.as_any(),
+            LvglWidget::Msgbox(this) => this.as_any(),
+            LvglWidget::Spinner(this) => this.as_any(),
+            LvglWidget::Calendar(this) => this.as_any(),
+            LvglWidget::List(this) => this.as_any(),
+            LvglWidget::Menu(this) => this.as_any(),
+            LvglWidget::Spinbox(this) => this.as_any(),
+            LvglWidget::Canvas(this) => this.as_any(),
+            LvglWidget::ImgButton(this) => this.as_any(),
+            LvglWidget::AnimImage(this) => this.as_any(),
+            LvglWidget::Gif(this) => this.as_any(),
 
             LvglWidget::Display() => &0 as &dyn Any,
         }
@@ -130,6 +228,21 @@ impl LvglWidget {
             LvglWidget::Qrcode(this) => this.get_uid(),
             LvglWidget::PixButton(this) => this.get_uid(),
             LvglWidget::Area(this) => this.get_uid(),
+            LvglWidget::Chart(this) => this.get_uid(),
+            LvglWidget::Table(this) => this.get_uid(),
+            LvglWidget::Tabview(this) => this.get_uid(),
+            LvglWidget::Page(this) => this.get_uid(),
+            LvglWidget::Tileview(this) => this.get_uid(),
+            LvglWidget::Msgbox(this) => this.get_uid(),
+            LvglWidget::Spinner(this) => this.get_uid(),
+            LvglWidget::Calendar(this) => this.get_uid(),
+            LvglWidget::List(this) => this.get_uid(),
+            LvglWidget::Menu(this) => this.get_uid(),
+            LvglWidget::Spinbox(this) => this.get_uid(),
+            LvglWidget::Canvas(this) => this.get_uid(),
+            LvglWidget::ImgButton(this) => this.get_uid(),
+            LvglWidget::AnimImage(this) => this.get_uid(),
+            LvglWidget::Gif(this) => this.get_uid(),
 
             LvglWidget::Display() => "Root",
         }
@@ -151,6 +264,7 @@ pub enum LvglEvent {
     DEFOCUSED,
     LEAVE,
     VALUE_CHANGED,
+    READY,
     UNKNOWN,
 }
 
@@ -169,6 +283,7 @@ impl LvglEvent {
             15 => Self::DEFOCUSED,
             16 => Self::LEAVE,
             28 => Self::VALUE_CHANGED,
+            31 => Self::READY,
             _ => Self::UNKNOWN,
         }
     }
@@ -228,3 +343,588 @@ impl LvglStates {
         }
     }
 }
+
+#[allow(non_camel_case_types)]
+pub enum LvglPart {
+    MAIN,
+    SCROLLBAR,
+    INDICATOR,
+    KNOB,
+    SELECTED,
+    ITEMS,
+    TICKS,
+    CURSOR,
+    CUSTOM_FIRST,
+    ANY,
+}
+
+impl LvglPart {
+    pub(crate) fn get_raw(&self) -> u32 {
+        match self {
+            LvglPart::MAIN => cglue::LV_PART_MAIN,
+            LvglPart::SCROLLBAR => cglue::LV_PART_SCROLLBAR,
+            LvglPart::INDICATOR => cglue::LV_PART_INDICATOR,
+            LvglPart::KNOB => cglue::LV_PART_KNOB,
+            LvglPart::SELECTED => cglue::LV_PART_SELECTED,
+            LvglPart::ITEMS => cglue::LV_PART_ITEMS,
+            LvglPart::TICKS => cglue::LV_PART_TICKS,
+            LvglPart::CURSOR => cglue::LV_PART_CURSOR,
+            LvglPart::CUSTOM_FIRST => cglue::LV_PART_CUSTOM_FIRST,
+            LvglPart::ANY => cglue::LV_PART_ANY,
+        }
+    }
+}
+
+// lvgl style selector, built from LvglPart | LvglState (ex: LvglPart::KNOB | LvglState::CHECKED)
+#[derive(Clone, Copy, Default)]
+pub struct LvglSelector {
+    pub(crate) handle: cglue::lv_style_selector_t,
+}
+
+impl LvglSelector {
+    pub fn new(part: LvglPart, state: LvglState) -> Self {
+        LvglSelector {
+            handle: part.get_raw() | state.get_raw() as u32,
+        }
+    }
+}
+
+impl From<LvglPart> for LvglSelector {
+    fn from(part: LvglPart) -> Self {
+        LvglSelector::new(part, LvglState::DEFAULT)
+    }
+}
+
+impl From<LvglState> for LvglSelector {
+    fn from(state: LvglState) -> Self {
+        LvglSelector::new(LvglPart::MAIN, state)
+    }
+}
+
+impl ops::BitOr<LvglState> for LvglPart {
+    type Output = LvglSelector;
+    fn bitor(self, state: LvglState) -> LvglSelector {
+        LvglSelector::new(self, state)
+    }
+}
+
+impl ops::BitOr<LvglPart> for LvglState {
+    type Output = LvglSelector;
+    fn bitor(self, part: LvglPart) -> LvglSelector {
+        LvglSelector::new(part, self)
+    }
+}
+
+// combine multiple states (ex: LvglState::CHECKED | LvglState::PRESSED)
+impl ops::BitOr<LvglState> for LvglState {
+    type Output = LvglSelector;
+    fn bitor(self, state: LvglState) -> LvglSelector {
+        LvglSelector {
+            handle: (self.get_raw() | state.get_raw()) as u32,
+        }
+    }
+}
+
+impl ops::BitOr<LvglState> for LvglSelector {
+    type Output = LvglSelector;
+    fn bitor(self, state: LvglState) -> LvglSelector {
+        LvglSelector {
+            handle: self.handle | state.get_raw() as u32,
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglChartType {
+    NONE,
+    LINE,
+    BAR,
+    SCATTER,
+}
+
+impl LvglChartType {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglChartType::NONE => cglue::LV_CHART_TYPE_NONE,
+            LvglChartType::LINE => cglue::LV_CHART_TYPE_LINE,
+            LvglChartType::BAR => cglue::LV_CHART_TYPE_BAR,
+            LvglChartType::SCATTER => cglue::LV_CHART_TYPE_SCATTER,
+        };
+        value as u8
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglChartAxis {
+    PRIMARY_Y,
+    SECONDARY_Y,
+    PRIMARY_X,
+    SECONDARY_X,
+}
+
+impl LvglChartAxis {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglChartAxis::PRIMARY_Y => cglue::LV_CHART_AXIS_PRIMARY_Y,
+            LvglChartAxis::SECONDARY_Y => cglue::LV_CHART_AXIS_SECONDARY_Y,
+            LvglChartAxis::PRIMARY_X => cglue::LV_CHART_AXIS_PRIMARY_X,
+            LvglChartAxis::SECONDARY_X => cglue::LV_CHART_AXIS_SECONDARY_X,
+        };
+        value as u8
+    }
+
+    // lvgl axis flags are 0,1,2,4 we use them as index in per axis tables
+    pub(crate) fn get_index(&self) -> usize {
+        match self {
+            LvglChartAxis::PRIMARY_Y => 0,
+            LvglChartAxis::SECONDARY_Y => 1,
+            LvglChartAxis::PRIMARY_X => 2,
+            LvglChartAxis::SECONDARY_X => 3,
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglTableCtrl {
+    MERGE_RIGHT,
+    TEXT_CROP,
+    CUSTOM_1,
+    CUSTOM_2,
+    CUSTOM_3,
+    CUSTOM_4,
+}
+
+impl LvglTableCtrl {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglTableCtrl::MERGE_RIGHT => cglue::LV_TABLE_CELL_CTRL_MERGE_RIGHT,
+            LvglTableCtrl::TEXT_CROP => cglue::LV_TABLE_CELL_CTRL_TEXT_CROP,
+            LvglTableCtrl::CUSTOM_1 => cglue::LV_TABLE_CELL_CTRL_CUSTOM_1,
+            LvglTableCtrl::CUSTOM_2 => cglue::LV_TABLE_CELL_CTRL_CUSTOM_2,
+            LvglTableCtrl::CUSTOM_3 => cglue::LV_TABLE_CELL_CTRL_CUSTOM_3,
+            LvglTableCtrl::CUSTOM_4 => cglue::LV_TABLE_CELL_CTRL_CUSTOM_4,
+        };
+        value as u8
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglDirection {
+    NONE,
+    LEFT,
+    RIGHT,
+    TOP,
+    BOTTOM,
+    HOR,
+    VER,
+    ALL,
+}
+
+impl LvglDirection {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglDirection::NONE => cglue::LV_DIR_NONE,
+            LvglDirection::LEFT => cglue::LV_DIR_LEFT,
+            LvglDirection::RIGHT => cglue::LV_DIR_RIGHT,
+            LvglDirection::TOP => cglue::LV_DIR_TOP,
+            LvglDirection::BOTTOM => cglue::LV_DIR_BOTTOM,
+            LvglDirection::HOR => cglue::LV_DIR_HOR,
+            LvglDirection::VER => cglue::LV_DIR_VER,
+            LvglDirection::ALL => cglue::LV_DIR_ALL,
+        };
+        value as u8
+    }
+
+    // lvgl directions are flags and can be combined
+    pub(crate) fn get_mask(directions: &[LvglDirection]) -> u8 {
+        let mut mask = 0;
+        for direction in directions {
+            mask |= direction.get_raw();
+        }
+        mask
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[allow(non_camel_case_types)]
+pub enum LvglLevel {
+    INFO,
+    WARNING,
+    ERROR,
+}
+
+impl LvglLevel {
+    // higher severity is displayed first
+    pub(crate) fn get_rank(&self) -> u8 {
+        match self {
+            LvglLevel::INFO => 0,
+            LvglLevel::WARNING => 1,
+            LvglLevel::ERROR => 2,
+        }
+    }
+
+    pub(crate) fn get_icon(&self) -> &'static [u8; 4] {
+        match self {
+            LvglLevel::INFO => LvglIcon::BELL,
+            LvglLevel::WARNING => LvglIcon::WARNING,
+            LvglLevel::ERROR => LvglIcon::CLOSE,
+        }
+    }
+
+    pub(crate) fn get_color(&self) -> LvglColor {
+        match self {
+            LvglLevel::INFO => LvglColor::BLUE(),
+            LvglLevel::WARNING => LvglColor::AMBER(),
+            LvglLevel::ERROR => LvglColor::RED(),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglImgButtonState {
+    RELEASED,
+    PRESSED,
+    DISABLED,
+    CHECKED_RELEASED,
+    CHECKED_PRESSED,
+    CHECKED_DISABLED,
+}
+
+impl LvglImgButtonState {
+    pub(crate) fn get_raw(&self) -> cglue::lv_imgbtn_state_t {
+        match self {
+            LvglImgButtonState::RELEASED => cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_RELEASED,
+            LvglImgButtonState::PRESSED => cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_PRESSED,
+            LvglImgButtonState::DISABLED => cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_DISABLED,
+            LvglImgButtonState::CHECKED_RELEASED => {
+                cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_CHECKED_RELEASED
+            }
+            LvglImgButtonState::CHECKED_PRESSED => {
+                cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_CHECKED_PRESSED
+            }
+            LvglImgButtonState::CHECKED_DISABLED => {
+                cglue::lv_imgbtn_state_t_LV_IMGBTN_STATE_CHECKED_DISABLED
+            }
+        }
+    }
+
+    pub(crate) fn all() -> [LvglImgButtonState; 6] {
+        [
+            LvglImgButtonState::RELEASED,
+            LvglImgButtonState::PRESSED,
+            LvglImgButtonState::DISABLED,
+            LvglImgButtonState::CHECKED_RELEASED,
+            LvglImgButtonState::CHECKED_PRESSED,
+            LvglImgButtonState::CHECKED_DISABLED,
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub enum LvglError {
+    InvalidPath(String),
+    Decode(String),
+    Filesystem(String),
+    BufferSize(usize, usize),
+    Unsupported(String),
+    StyleSheet(String),
+}
+
+impl fmt::Display for LvglError {
+    fn fmt(&self, format: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LvglError::InvalidPath(path) => write!(format, "invalid image path:{}", path),
+            LvglError::Decode(error) => write!(format, "image decoding fail:{}", error),
+            LvglError::Filesystem(error) => write!(format, "filesystem error:{}", error),
+            LvglError::BufferSize(expected, received) => write!(
+                format,
+                "invalid buffer size expected:{} received:{}",
+                expected, received
+            ),
+            LvglError::Unsupported(error) => write!(format, "unsupported:{}", error),
+            LvglError::StyleSheet(error) => write!(format, "style sheet error:{}", error),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, PartialEq)]
+pub enum LvglColorFormat {
+    TRUE_COLOR,
+    TRUE_COLOR_ALPHA,
+    TRUE_COLOR_CHROMA_KEYED,
+    ALPHA_8BIT,
+}
+
+impl LvglColorFormat {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglColorFormat::TRUE_COLOR => cglue::LV_IMG_CF_TRUE_COLOR,
+            LvglColorFormat::TRUE_COLOR_ALPHA => cglue::LV_IMG_CF_TRUE_COLOR_ALPHA,
+            LvglColorFormat::TRUE_COLOR_CHROMA_KEYED => cglue::LV_IMG_CF_TRUE_COLOR_CHROMA_KEYED,
+            LvglColorFormat::ALPHA_8BIT => cglue::LV_IMG_CF_ALPHA_8BIT,
+        };
+        value as u8
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub enum LvglStyleProp {
+    WIDTH,
+    HEIGHT,
+    X,
+    Y,
+    TRANSLATE_X,
+    TRANSLATE_Y,
+    TRANSFORM_WIDTH,
+    TRANSFORM_HEIGHT,
+    TRANSFORM_ZOOM,
+    TRANSFORM_ANGLE,
+    PAD_TOP,
+    PAD_BOTTOM,
+    PAD_LEFT,
+    PAD_RIGHT,
+    RADIUS,
+    OPA,
+    BG_COLOR,
+    BG_OPA,
+    BG_GRAD_COLOR,
+    BORDER_COLOR,
+    BORDER_OPA,
+    BORDER_WIDTH,
+    OUTLINE_COLOR,
+    OUTLINE_OPA,
+    OUTLINE_WIDTH,
+    OUTLINE_PAD,
+    SHADOW_COLOR,
+    SHADOW_OPA,
+    SHADOW_WIDTH,
+    SHADOW_OFS_X,
+    SHADOW_OFS_Y,
+    SHADOW_SPREAD,
+    IMG_OPA,
+    IMG_RECOLOR,
+    IMG_RECOLOR_OPA,
+    LINE_COLOR,
+    LINE_OPA,
+    LINE_WIDTH,
+    ARC_COLOR,
+    ARC_OPA,
+    ARC_WIDTH,
+    TEXT_COLOR,
+    TEXT_OPA,
+    TEXT_LETTER_SPACE,
+    TEXT_LINE_SPACE,
+}
+
+impl LvglStyleProp {
+    pub(crate) fn get_raw(&self) -> cglue::lv_style_prop_t {
+        let value = match self {
+            LvglStyleProp::WIDTH => cglue::LV_STYLE_WIDTH,
+            LvglStyleProp::HEIGHT => cglue::LV_STYLE_HEIGHT,
+            LvglStyleProp::X => cglue::LV_STYLE_X,
+            LvglStyleProp::Y => cglue::LV_STYLE_Y,
+            LvglStyleProp::TRANSLATE_X => cglue::LV_STYLE_TRANSLATE_X,
+            LvglStyleProp::TRANSLATE_Y => cglue::LV_STYLE_TRANSLATE_Y,
+            LvglStyleProp::TRANSFORM_WIDTH => cglue::LV_STYLE_TRANSFORM_WIDTH,
+            LvglStyleProp::TRANSFORM_HEIGHT => cglue::LV_STYLE_TRANSFORM_HEIGHT,
+            LvglStyleProp::TRANSFORM_ZOOM => cglue::LV_STYLE_TRANSFORM_ZOOM,
+            LvglStyleProp::TRANSFORM_ANGLE => cglue::LV_STYLE_TRANSFORM_ANGLE,
+            LvglStyleProp::PAD_TOP => cglue::LV_STYLE_PAD_TOP,
+            LvglStyleProp::PAD_BOTTOM => cglue::LV_STYLE_PAD_BOTTOM,
+            LvglStyleProp::PAD_LEFT => cglue::LV_STYLE_PAD_LEFT,
+            LvglStyleProp::PAD_RIGHT => cglue::LV_STYLE_PAD_RIGHT,
+            LvglStyleProp::RADIUS => cglue::LV_STYLE_RADIUS,
+            LvglStyleProp::OPA => cglue::LV_STYLE_OPA,
+            LvglStyleProp::BG_COLOR => cglue::LV_STYLE_BG_COLOR,
+            LvglStyleProp::BG_OPA => cglue::LV_STYLE_BG_OPA,
+            LvglStyleProp::BG_GRAD_COLOR => cglue::LV_STYLE_BG_GRAD_COLOR,
+            LvglStyleProp::BORDER_COLOR => cglue::LV_STYLE_BORDER_COLOR,
+            LvglStyleProp::BORDER_OPA => cglue::LV_STYLE_BORDER_OPA,
+            LvglStyleProp::BORDER_WIDTH => cglue::LV_STYLE_BORDER_WIDTH,
+            LvglStyleProp::OUTLINE_COLOR => cglue::LV_STYLE_OUTLINE_COLOR,
+            LvglStyleProp::OUTLINE_OPA => cglue::LV_STYLE_OUTLINE_OPA,
+            LvglStyleProp::OUTLINE_WIDTH => cglue::LV_STYLE_OUTLINE_WIDTH,
+            LvglStyleProp::OUTLINE_PAD => cglue::LV_STYLE_OUTLINE_PAD,
+            LvglStyleProp::SHADOW_COLOR => cglue::LV_STYLE_SHADOW_COLOR,
+            LvglStyleProp::SHADOW_OPA => cglue::LV_STYLE_SHADOW_OPA,
+            LvglStyleProp::SHADOW_WIDTH => cglue::LV_STYLE_SHADOW_WIDTH,
+            LvglStyleProp::SHADOW_OFS_X => cglue::LV_STYLE_SHADOW_OFS_X,
+            LvglStyleProp::SHADOW_OFS_Y => cglue::LV_STYLE_SHADOW_OFS_Y,
+            LvglStyleProp::SHADOW_SPREAD => cglue::LV_STYLE_SHADOW_SPREAD,
+            LvglStyleProp::IMG_OPA => cglue::LV_STYLE_IMG_OPA,
+            LvglStyleProp::IMG_RECOLOR => cglue::LV_STYLE_IMG_RECOLOR,
+            LvglStyleProp::IMG_RECOLOR_OPA => cglue::LV_STYLE_IMG_RECOLOR_OPA,
+            LvglStyleProp::LINE_COLOR => cglue::LV_STYLE_LINE_COLOR,
+            LvglStyleProp::LINE_OPA => cglue::LV_STYLE_LINE_OPA,
+            LvglStyleProp::LINE_WIDTH => cglue::LV_STYLE_LINE_WIDTH,
+            LvglStyleProp::ARC_COLOR => cglue::LV_STYLE_ARC_COLOR,
+            LvglStyleProp::ARC_OPA => cglue::LV_STYLE_ARC_OPA,
+            LvglStyleProp::ARC_WIDTH => cglue::LV_STYLE_ARC_WIDTH,
+            LvglStyleProp::TEXT_COLOR => cglue::LV_STYLE_TEXT_COLOR,
+            LvglStyleProp::TEXT_OPA => cglue::LV_STYLE_TEXT_OPA,
+            LvglStyleProp::TEXT_LETTER_SPACE => cglue::LV_STYLE_TEXT_LETTER_SPACE,
+            LvglStyleProp::TEXT_LINE_SPACE => cglue::LV_STYLE_TEXT_LINE_SPACE,
+        };
+        value as cglue::lv_style_prop_t
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub enum LvglEasing {
+    LINEAR,
+    EASE_IN,
+    EASE_OUT,
+    EASE_IN_OUT,
+    OVERSHOOT,
+    BOUNCE,
+    STEP,
+}
+
+impl LvglEasing {
+    pub(crate) fn get_path(&self) -> cglue::lv_anim_path_cb_t {
+        match self {
+            LvglEasing::LINEAR => Some(cglue::lv_anim_path_linear),
+            LvglEasing::EASE_IN => Some(cglue::lv_anim_path_ease_in),
+            LvglEasing::EASE_OUT => Some(cglue::lv_anim_path_ease_out),
+            LvglEasing::EASE_IN_OUT => Some(cglue::lv_anim_path_ease_in_out),
+            LvglEasing::OVERSHOOT => Some(cglue::lv_anim_path_overshoot),
+            LvglEasing::BOUNCE => Some(cglue::lv_anim_path_bounce),
+            LvglEasing::STEP => Some(cglue::lv_anim_path_step),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglTextAlign {
+    AUTO,
+    LEFT,
+    CENTER,
+    RIGHT,
+}
+
+impl LvglTextAlign {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglTextAlign::AUTO => cglue::LV_TEXT_ALIGN_AUTO,
+            LvglTextAlign::LEFT => cglue::LV_TEXT_ALIGN_LEFT,
+            LvglTextAlign::CENTER => cglue::LV_TEXT_ALIGN_CENTER,
+            LvglTextAlign::RIGHT => cglue::LV_TEXT_ALIGN_RIGHT,
+        };
+        value as u8
+    }
+}
+
+#[allow(non_camel_case_types)]
+pub enum LvglTextDecor {
+    NONE,
+    UNDERLINE,
+    STRIKETHROUGH,
+}
+
+impl LvglTextDecor {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglTextDecor::NONE => cglue::LV_TEXT_DECOR_NONE,
+            LvglTextDecor::UNDERLINE => cglue::LV_TEXT_DECOR_UNDERLINE,
+            LvglTextDecor::STRIKETHROUGH => cglue::LV_TEXT_DECOR_STRIKETHROUGH,
+        };
+        value as u8
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub enum LvglGradDir {
+    NONE,
+    VER,
+    HOR,
+}
+
+impl LvglGradDir {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglGradDir::NONE => cglue::LV_GRAD_DIR_NONE,
+            LvglGradDir::VER => cglue::LV_GRAD_DIR_VER,
+            LvglGradDir::HOR => cglue::LV_GRAD_DIR_HOR,
+        };
+        value as u8
+    }
+}
+
+// dithering requires LV_DITHER_GRADIENT, mostly useful on 16 bit panels
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub enum LvglDither {
+    NONE,
+    ORDERED,
+    ERR_DIFF,
+}
+
+impl LvglDither {
+    pub(crate) fn get_raw(&self) -> u8 {
+        let value = match self {
+            LvglDither::NONE => cglue::LV_DITHER_NONE,
+            LvglDither::ORDERED => cglue::LV_DITHER_ORDERED,
+            LvglDither::ERR_DIFF => cglue::LV_DITHER_ERR_DIFF,
+        };
+        value as u8
+    }
+}
+
+// lvgl widget classes, used by custom themes to style per widget type
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy)]
+pub enum LvglClass {
+    OBJ,
+    BUTTON,
+    LABEL,
+    IMAGE,
+    LINE,
+    ARC,
+    BAR,
+    SWITCH,
+    LED,
+    TEXTAREA,
+    TABLE,
+    METER,
+    CHART,
+    SPINNER,
+    CALENDAR,
+    LIST,
+    MSGBOX,
+    TABVIEW,
+    SPINBOX,
+    CANVAS,
+}
+
+impl LvglClass {
+    pub(crate) fn get_raw(&self) -> *const cglue::lv_obj_class_t {
+        unsafe {
+            match self {
+                LvglClass::OBJ => &cglue::lv_obj_class,
+                LvglClass::BUTTON => &cglue::lv_btn_class,
+                LvglClass::LABEL => &cglue::lv_label_class,
+                LvglClass::IMAGE => &cglue::lv_img_class,
+                LvglClass::LINE => &cglue::lv_line_class,
+                LvglClass::ARC => &cglue::lv_arc_class,
+                LvglClass::BAR => &cglue::lv_bar_class,
+                LvglClass::SWITCH => &cglue::lv_switch_class,
+                LvglClass::LED => &cglue::lv_led_class,
+                LvglClass::TEXTAREA => &cglue::lv_textarea_class,
+                LvglClass::TABLE => &cglue::lv_table_class,
+                LvglClass::METER => &cglue::lv_meter_class,
+                LvglClass::CHART => &cglue::lv_chart_class,
+                LvglClass::SPINNER => &cglue::lv_spinner_class,
+                LvglClass::CALENDAR => &cglue::lv_calendar_class,
+                LvglClass::LIST => &cglue::lv_list_class,
+                LvglClass::MSGBOX => &cglue::lv_msgbox_class,
+                LvglClass::TABVIEW => &cglue::lv_tabview_class,
+                LvglClass::SPINBOX => &cglue::lv_spinbox_class,
+                LvglClass::CANVAS => &cglue::lv_canvas_class,
+            }
+        }
+    }
+}
@@ -37,6 +37,25 @@ mod core;
 #[path = "extra-widgets.rs"]
 mod extra;
 
+#[path = "overlay-widgets.rs"]
+mod overlay;
+
+#[path = "fs-driver.rs"]
+mod fsdrv;
+
+#[path = "style-methods.rs"]
+mod style;
+
+#[path = "theme-methods.rs"]
+mod theme;
+
+#[path = "anim-methods.rs"]
+mod anim;
+
+#[cfg(feature = "stylesheet")]
+#[path = "style-sheet.rs"]
+mod sheet;
+
 pub mod prelude {
     pub(crate) use crate::capi::*;
     pub use crate::capi::get_time;
@@ -45,4 +64,11 @@ pub mod prelude {
     pub use crate::generic::*;
     pub use crate::core::*;
     pub use crate::extra::*;
+    pub use crate::overlay::*;
+    pub use crate::fsdrv::*;
+    pub use crate::style::*;
+    pub use crate::theme::*;
+    pub use crate::anim::*;
+    #[cfg(feature = "stylesheet")]
+    pub use crate::sheet::*;
 }
\ No newline at end of file
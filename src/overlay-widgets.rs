@@ -0,0 +1,281 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::prelude::*;
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::ffi::CString;
+use std::os::raw;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+// lvgl is not thread safe, notifications are queued and later drained from lvgl timer
+static NOTIFY_QUEUE: Mutex<VecDeque<(LvglLevel, String)>> = Mutex::new(VecDeque::new());
+static NOTIFY_CAPACITY: AtomicUsize = AtomicUsize::new(8);
+
+// may be called from any thread, oldest pending notification is dropped when queue is full
+pub fn notify(level: LvglLevel, text: &str) {
+    let mut queue = match NOTIFY_QUEUE.lock() {
+        Err(_) => return,
+        Ok(value) => value,
+    };
+    if queue.len() >= NOTIFY_CAPACITY.load(Ordering::Relaxed) {
+        queue.pop_front();
+    }
+    queue.push_back((level, text.to_string()));
+}
+
+struct ToastEntry {
+    rank: u8,
+    handle: *mut cglue::_lv_obj_t,
+}
+
+pub struct LvglToast {
+    container: *mut cglue::_lv_obj_t,
+    width: i16,
+    timeout: Cell<u32>,
+    fade: Cell<u32>,
+    capacity: usize,
+    toasts: RefCell<Vec<ToastEntry>>,
+}
+
+extern "C" fn toast_timer_cb(timer: *mut cglue::lv_timer_t) {
+    let toast = unsafe { &*((*timer).user_data as *const LvglToast) };
+    let pending: Vec<(LvglLevel, String)> = match NOTIFY_QUEUE.lock() {
+        Err(_) => return,
+        Ok(mut queue) => queue.drain(..).collect(),
+    };
+    for (level, text) in pending {
+        toast.push(level, text.as_str());
+    }
+}
+
+extern "C" fn toast_event_cb(event: *mut cglue::lv_event_t) {
+    unsafe {
+        let toast = &*(cglue::lv_event_get_user_data(event) as *const LvglToast);
+        let target = cglue::lv_event_get_target(event);
+        let code = cglue::lv_event_get_code(event);
+
+        if code == cglue::lv_event_code_t_LV_EVENT_CLICKED {
+            // object is deleted from its own event, let lvgl do it later
+            cglue::lv_obj_del_async(target);
+        } else if code == cglue::lv_event_code_t_LV_EVENT_DELETE {
+            toast
+                .toasts
+                .borrow_mut()
+                .retain(|entry| entry.handle != target);
+        }
+    }
+}
+
+impl LvglToast {
+    // should be called once from lvgl thread before any notify() is displayed
+    pub fn start(width: i16, timeout: u32, capacity: usize) -> &'static Self {
+        NOTIFY_CAPACITY.store(capacity, Ordering::Relaxed);
+        unsafe {
+            let layer = cglue::lv_disp_get_layer_top(cglue::lv_disp_get_default());
+            let container = cglue::lv_obj_create(layer);
+            cglue::lv_obj_remove_style_all(container);
+            cglue::lv_obj_set_size(container, width, cglue::lv_size_contend);
+            cglue::lv_obj_align(container, cglue::LV_ALIGN_TOP_RIGHT as u8, -10, 10);
+            cglue::lv_obj_set_flex_flow(container, cglue::lv_flex_flow_t_LV_FLEX_FLOW_COLUMN);
+            cglue::lv_obj_set_style_pad_row(container, 5, 0);
+            cglue::lv_obj_clear_flag(container, cglue::LV_OBJ_FLAG_CLICKABLE);
+            cglue::lv_obj_clear_flag(container, cglue::LV_OBJ_FLAG_SCROLLABLE);
+
+            let toast = Box::leak(Box::new(LvglToast {
+                container,
+                width,
+                timeout: Cell::new(timeout),
+                fade: Cell::new(500),
+                capacity,
+                toasts: RefCell::new(Vec::new()),
+            }));
+
+            cglue::lv_timer_create(
+                Some(toast_timer_cb),
+                100,
+                toast as *const _ as *mut raw::c_void,
+            );
+            toast
+        }
+    }
+
+    // display duration in ms before fading out
+    pub fn set_timeout(&self, timeout: u32) -> &Self {
+        self.timeout.set(timeout);
+        self
+    }
+
+    // fade out duration in ms before a toast is removed
+    pub fn set_fade(&self, fade: u32) -> &Self {
+        self.fade.set(fade);
+        self
+    }
+
+    fn push(&self, level: LvglLevel, text: &str) {
+        let rank = level.get_rank();
+
+        // when full drop the oldest toast with lowest severity
+        let victim = {
+            let toasts = self.toasts.borrow();
+            if toasts.len() < self.capacity {
+                None
+            } else {
+                let lowest = toasts.iter().map(|entry| entry.rank).min().unwrap_or(0);
+                if rank < lowest {
+                    return; // every displayed toast is more important
+                }
+                toasts
+                    .iter()
+                    .rev()
+                    .find(|entry| entry.rank == lowest)
+                    .map(|entry| entry.handle)
+            }
+        };
+        if let Some(handle) = victim {
+            // entry is removed from list by delete event
+            unsafe { cglue::lv_obj_del(handle) };
+        }
+
+        let icon = level.get_icon();
+        let label = format!("{}  {}", String::from_utf8_lossy(&icon[0..3]), text);
+        let label = match CString::new(label) {
+            Err(_) => CString::new("Non UTF8 text").unwrap(),
+            Ok(value) => value,
+        };
+
+        unsafe {
+            let handle = cglue::lv_obj_create(self.container);
+            cglue::lv_obj_set_size(handle, self.width, cglue::lv_size_contend);
+            cglue::lv_obj_set_style_bg_color(handle, level.get_color().handle, 0);
+            cglue::lv_obj_set_style_bg_opa(handle, cglue::LV_OPA_90 as u8, 0);
+            cglue::lv_obj_clear_flag(handle, cglue::LV_OBJ_FLAG_SCROLLABLE);
+
+            let text = cglue::lv_label_create(handle);
+            cglue::lv_obj_set_width(text, self.width - 40);
+            cglue::lv_label_set_long_mode(text, cglue::LV_LABEL_LONG_WRAP as u8);
+            cglue::lv_label_set_text(text, label.as_ptr());
+
+            // toasts are sorted by severity, newest first within a level
+            let index = {
+                let mut toasts = self.toasts.borrow_mut();
+                let index = toasts.iter().filter(|entry| entry.rank > rank).count();
+                toasts.insert(index, ToastEntry { rank, handle });
+                index
+            };
+            cglue::lv_obj_move_to_index(handle, index as i32);
+
+            cglue::lv_obj_add_event_cb(
+                handle,
+                Some(toast_event_cb),
+                cglue::lv_event_code_t_LV_EVENT_ALL,
+                self as *const _ as *mut raw::c_void,
+            );
+            cglue::lv_obj_fade_out(handle, self.fade.get(), self.timeout.get());
+            cglue::lv_obj_del_delayed(handle, self.timeout.get() + self.fade.get());
+        }
+    }
+}
+
+pub struct LvglBusy {
+    handle: *mut cglue::_lv_obj_t,
+    spinner: *mut cglue::_lv_obj_t,
+    label: *mut cglue::_lv_obj_t,
+}
+
+impl LvglBusy {
+    // overlay is created hidden on top layer, use raise/clear to show it
+    pub fn new(font: &LvglFont) -> &'static Self {
+        unsafe {
+            let display = cglue::lv_disp_get_default();
+            let layer = cglue::lv_disp_get_layer_top(display);
+
+            // full screen clickable object catches every input while raised
+            let handle = cglue::lv_obj_create(layer);
+            cglue::lv_obj_remove_style_all(handle);
+            cglue::lv_obj_set_size(
+                handle,
+                cglue::lv_disp_get_hor_res(display),
+                cglue::lv_disp_get_ver_res(display),
+            );
+            cglue::lv_obj_set_style_bg_color(handle, cglue::lv_color_mk(0, 0, 0), 0);
+            cglue::lv_obj_set_style_bg_opa(handle, cglue::LV_OPA_50 as u8, 0);
+            cglue::lv_obj_add_flag(handle, cglue::LV_OBJ_FLAG_CLICKABLE);
+            cglue::lv_obj_clear_flag(handle, cglue::LV_OBJ_FLAG_SCROLLABLE);
+            cglue::lv_obj_add_flag(handle, cglue::LV_OBJ_FLAG_HIDDEN);
+
+            let spinner = cglue::lv_spinner_create(handle, 1000, 60);
+            cglue::lv_obj_set_size(spinner, 80, 80);
+            cglue::lv_obj_align(spinner, cglue::LV_ALIGN_CENTER as u8, 0, -20);
+
+            let label = cglue::lv_label_create(handle);
+            cglue::lv_obj_set_style_text_font(
+                label,
+                font as *const _ as *const cglue::lv_font_t,
+                0,
+            );
+            cglue::lv_obj_set_style_text_color(label, cglue::lv_color_mk(0xFF, 0xFF, 0xFF), 0);
+            cglue::lv_obj_align(label, cglue::LV_ALIGN_CENTER as u8, 0, 50);
+            cglue::lv_obj_add_flag(label, cglue::LV_OBJ_FLAG_HIDDEN);
+
+            Box::leak(Box::new(LvglBusy {
+                handle,
+                spinner,
+                label,
+            }))
+        }
+    }
+
+    pub fn set_color(&self, color: LvglColor) -> &Self {
+        unsafe {
+            cglue::lv_obj_set_style_arc_color(self.spinner, color.handle, cglue::LV_PART_INDICATOR);
+        }
+        self
+    }
+
+    // dim screen and block input until clear is called
+    pub fn raise(&self, message: Option<&str>) -> &Self {
+        unsafe {
+            match message {
+                None => cglue::lv_obj_add_flag(self.label, cglue::LV_OBJ_FLAG_HIDDEN),
+                Some(text) => {
+                    let text = match CString::new(text) {
+                        Err(_) => CString::new("Non UTF8 text").unwrap(),
+                        Ok(value) => value,
+                    };
+                    cglue::lv_label_set_text(self.label, text.as_ptr());
+                    cglue::lv_obj_align(self.label, cglue::LV_ALIGN_CENTER as u8, 0, 50);
+                    cglue::lv_obj_clear_flag(self.label, cglue::LV_OBJ_FLAG_HIDDEN);
+                }
+            }
+            cglue::lv_obj_move_to_index(self.handle, -1);
+            cglue::lv_obj_clear_flag(self.handle, cglue::LV_OBJ_FLAG_HIDDEN);
+        }
+        self
+    }
+
+    pub fn clear(&self) -> &Self {
+        unsafe {
+            cglue::lv_obj_add_flag(self.handle, cglue::LV_OBJ_FLAG_HIDDEN);
+        }
+        self
+    }
+
+    pub fn is_raised(&self) -> bool {
+        unsafe { !cglue::lv_obj_has_flag(self.handle, cglue::LV_OBJ_FLAG_HIDDEN) }
+    }
+}
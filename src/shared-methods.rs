@@ -93,6 +93,30 @@ macro_rules! impl_widget_trait {
     };
 }
 
+macro_rules! impl_local_color {
+    ($method:ident, $setter:ident) => {
+        fn $method(&self, color: LvglColor, selector: impl Into<LvglSelector>) -> &Self
+        where
+            Self: LvglCommon,
+        {
+            unsafe { cglue::$setter(self.get_handle(), color.handle, selector.into().handle) };
+            self
+        }
+    };
+}
+
+macro_rules! impl_local_value {
+    ($method:ident, $setter:ident, $type:ty) => {
+        fn $method(&self, value: $type, selector: impl Into<LvglSelector>) -> &Self
+        where
+            Self: LvglCommon,
+        {
+            unsafe { cglue::$setter(self.get_handle(), value, selector.into().handle) };
+            self
+        }
+    };
+}
+
 pub trait LvglMethod {
     fn set_size(&self, width: i16, height: i16) -> &Self
     where
@@ -131,9 +155,21 @@ pub trait LvglMethod {
     where
         Self: LvglCommon,
     {
-        let style = self.get_style();
+        self.set_color_for(color, LvglSelector::default())
+    }
+
+    // default selector updates widget own style, any other part/state uses local style
+    fn set_color_for(&self, color: LvglColor, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into().handle;
         unsafe {
-            cglue::lv_style_set_text_color(style, color.handle);
+            if selector == 0 {
+                cglue::lv_style_set_text_color(self.get_style(), color.handle);
+            } else {
+                cglue::lv_obj_set_style_text_color(self.get_handle(), color.handle, selector);
+            }
         }
         self
     }
@@ -142,10 +178,29 @@ pub trait LvglMethod {
     where
         Self: LvglCommon,
     {
-        let style = self.get_style();
+        self.set_border_for(width, color, LvglSelector::default())
+    }
+
+    fn set_border_for(
+        &self,
+        width: i16,
+        color: LvglColor,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into().handle;
         unsafe {
-            cglue::lv_style_set_border_width(style, width);
-            cglue::lv_style_set_border_color(style, color.handle);
+            if selector == 0 {
+                let style = self.get_style();
+                cglue::lv_style_set_border_width(style, width);
+                cglue::lv_style_set_border_color(style, color.handle);
+            } else {
+                let handle = self.get_handle();
+                cglue::lv_obj_set_style_border_width(handle, width, selector);
+                cglue::lv_obj_set_style_border_color(handle, color.handle, selector);
+            }
         }
         self
     }
@@ -154,12 +209,35 @@ pub trait LvglMethod {
     where
         Self: LvglCommon,
     {
-        let style = self.get_style();
+        self.set_padding_for(top, botton, right, left, LvglSelector::default())
+    }
+
+    fn set_padding_for(
+        &self,
+        top: i16,
+        botton: i16,
+        right: i16,
+        left: i16,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into().handle;
         unsafe {
-            cglue::lv_style_set_pad_top(style, top);
-            cglue::lv_style_set_pad_bottom(style, botton);
-            cglue::lv_style_set_pad_right(style, right);
-            cglue::lv_style_set_pad_left(style, left);
+            if selector == 0 {
+                let style = self.get_style();
+                cglue::lv_style_set_pad_top(style, top);
+                cglue::lv_style_set_pad_bottom(style, botton);
+                cglue::lv_style_set_pad_right(style, right);
+                cglue::lv_style_set_pad_left(style, left);
+            } else {
+                let handle = self.get_handle();
+                cglue::lv_obj_set_style_pad_top(handle, top, selector);
+                cglue::lv_obj_set_style_pad_bottom(handle, botton, selector);
+                cglue::lv_obj_set_style_pad_right(handle, right, selector);
+                cglue::lv_obj_set_style_pad_left(handle, left, selector);
+            }
         }
         self
     }
@@ -183,13 +261,15 @@ pub trait LvglMethod {
     where
         Self: LvglCommon,
     {
-        let handle = self.get_handle();
+        self.set_radius_for(cglue::LV_RADIUS_CIRCLE as i16, LvglSelector::default())
+    }
+
+    fn set_radius_for(&self, radius: i16, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
         unsafe {
-            cglue::lv_obj_set_style_radius(
-                handle,
-                cglue::LV_RADIUS_CIRCLE as i16,
-                cglue::LV_STATE_DEFAULT,
-            );
+            cglue::lv_obj_set_style_radius(self.get_handle(), radius, selector.into().handle);
         }
         self
     }
@@ -225,16 +305,383 @@ pub trait LvglMethod {
         self
     }
 
+    // historical 50% opacity, use set_background_for to choose it
     fn set_background(&self, color: LvglColor) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        self.set_background_for(color, cglue::LV_OPA_50 as u8, LvglSelector::default())
+    }
+
+    fn set_background_for(
+        &self,
+        color: LvglColor,
+        opacity: u8,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
     where
         Self: LvglCommon,
     {
         let handle = self.get_handle();
-        let style = self.get_style();
+        let selector = selector.into().handle;
         unsafe {
-            cglue::lv_style_set_bg_color(style, color.handle);
-            cglue::lv_style_set_bg_opa(style, cglue::LV_OPA_50 as u8);
-            cglue::lv_obj_add_style(handle, style, cglue::LV_STATE_DEFAULT);
+            if selector == 0 {
+                let style = self.get_style();
+                cglue::lv_style_set_bg_color(style, color.handle);
+                cglue::lv_style_set_bg_opa(style, opacity);
+                cglue::lv_obj_add_style(handle, style, cglue::LV_STATE_DEFAULT);
+            } else {
+                cglue::lv_obj_set_style_bg_color(handle, color.handle, selector);
+                cglue::lv_obj_set_style_bg_opa(handle, opacity, selector);
+            }
+        }
+        self
+    }
+
+    // shared style, later changes are reflected on every widget using it
+    fn add_style(&self, style: &LvglStyle, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        style.set_attached();
+        unsafe {
+            cglue::lv_obj_add_style(
+                self.get_handle(),
+                style.get_handle(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn remove_style(&self, style: &LvglStyle, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_remove_style(
+                self.get_handle(),
+                style.get_handle(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    // unknown class is ignored, check with LvglStyleSheet::get_class when needed
+    #[cfg(feature = "stylesheet")]
+    fn add_class(
+        &self,
+        sheet: &LvglStyleSheet,
+        name: &str,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        if let Ok(style) = sheet.get_class(name) {
+            self.add_style(style, selector);
+        }
+        self
+    }
+
+    // per part/state local style properties (ex: LvglPart::INDICATOR | LvglState::PRESSED)
+    impl_local_value!(set_style_width, lv_obj_set_style_width, i16);
+    impl_local_value!(set_style_min_width, lv_obj_set_style_min_width, i16);
+    impl_local_value!(set_style_max_width, lv_obj_set_style_max_width, i16);
+    impl_local_value!(set_style_height, lv_obj_set_style_height, i16);
+    impl_local_value!(set_style_min_height, lv_obj_set_style_min_height, i16);
+    impl_local_value!(set_style_max_height, lv_obj_set_style_max_height, i16);
+    impl_local_value!(set_style_x, lv_obj_set_style_x, i16);
+    impl_local_value!(set_style_y, lv_obj_set_style_y, i16);
+    impl_local_value!(set_style_pad_top, lv_obj_set_style_pad_top, i16);
+    impl_local_value!(set_style_pad_bottom, lv_obj_set_style_pad_bottom, i16);
+    impl_local_value!(set_style_pad_left, lv_obj_set_style_pad_left, i16);
+    impl_local_value!(set_style_pad_right, lv_obj_set_style_pad_right, i16);
+    impl_local_value!(set_style_pad_row, lv_obj_set_style_pad_row, i16);
+    impl_local_value!(set_style_pad_column, lv_obj_set_style_pad_column, i16);
+    impl_local_color!(set_style_bg_color, lv_obj_set_style_bg_color);
+    impl_local_value!(set_style_bg_opa, lv_obj_set_style_bg_opa, u8);
+    impl_local_color!(set_style_border_color, lv_obj_set_style_border_color);
+    impl_local_value!(set_style_border_opa, lv_obj_set_style_border_opa, u8);
+    impl_local_value!(set_style_border_width, lv_obj_set_style_border_width, i16);
+    impl_local_value!(set_style_border_post, lv_obj_set_style_border_post, bool);
+    impl_local_color!(set_style_text_color, lv_obj_set_style_text_color);
+    impl_local_value!(set_style_text_opa, lv_obj_set_style_text_opa, u8);
+    impl_local_color!(set_style_line_color, lv_obj_set_style_line_color);
+    impl_local_value!(set_style_line_opa, lv_obj_set_style_line_opa, u8);
+    impl_local_value!(set_style_line_width, lv_obj_set_style_line_width, i16);
+    impl_local_value!(set_style_line_rounded, lv_obj_set_style_line_rounded, bool);
+    impl_local_color!(set_style_arc_color, lv_obj_set_style_arc_color);
+    impl_local_value!(set_style_arc_opa, lv_obj_set_style_arc_opa, u8);
+    impl_local_value!(set_style_arc_width, lv_obj_set_style_arc_width, i16);
+    impl_local_value!(set_style_arc_rounded, lv_obj_set_style_arc_rounded, bool);
+    impl_local_color!(set_style_img_recolor, lv_obj_set_style_img_recolor);
+    impl_local_value!(
+        set_style_img_recolor_opa,
+        lv_obj_set_style_img_recolor_opa,
+        u8
+    );
+    impl_local_value!(set_style_img_opa, lv_obj_set_style_img_opa, u8);
+
+    impl_local_value!(set_style_radius, lv_obj_set_style_radius, i16);
+    impl_local_value!(set_style_opa, lv_obj_set_style_opa, u8);
+    impl_local_value!(set_style_clip_corner, lv_obj_set_style_clip_corner, bool);
+    impl_local_color!(set_style_shadow_color, lv_obj_set_style_shadow_color);
+    impl_local_value!(set_style_shadow_opa, lv_obj_set_style_shadow_opa, u8);
+    impl_local_value!(set_style_shadow_width, lv_obj_set_style_shadow_width, i16);
+    impl_local_value!(set_style_shadow_ofs_x, lv_obj_set_style_shadow_ofs_x, i16);
+    impl_local_value!(set_style_shadow_ofs_y, lv_obj_set_style_shadow_ofs_y, i16);
+    impl_local_value!(set_style_shadow_spread, lv_obj_set_style_shadow_spread, i16);
+    impl_local_color!(set_style_outline_color, lv_obj_set_style_outline_color);
+    impl_local_value!(set_style_outline_opa, lv_obj_set_style_outline_opa, u8);
+    impl_local_value!(set_style_outline_width, lv_obj_set_style_outline_width, i16);
+    impl_local_value!(set_style_outline_pad, lv_obj_set_style_outline_pad, i16);
+    impl_local_value!(
+        set_style_text_letter_space,
+        lv_obj_set_style_text_letter_space,
+        i16
+    );
+    impl_local_value!(
+        set_style_text_line_space,
+        lv_obj_set_style_text_line_space,
+        i16
+    );
+    impl_local_value!(set_style_bg_img_opa, lv_obj_set_style_bg_img_opa, u8);
+    impl_local_color!(set_style_bg_img_recolor, lv_obj_set_style_bg_img_recolor);
+    impl_local_value!(
+        set_style_bg_img_recolor_opa,
+        lv_obj_set_style_bg_img_recolor_opa,
+        u8
+    );
+    impl_local_value!(set_style_bg_img_tiled, lv_obj_set_style_bg_img_tiled, bool);
+    impl_local_value!(
+        set_style_transform_width,
+        lv_obj_set_style_transform_width,
+        i16
+    );
+    impl_local_value!(
+        set_style_transform_height,
+        lv_obj_set_style_transform_height,
+        i16
+    );
+    impl_local_value!(
+        set_style_transform_zoom,
+        lv_obj_set_style_transform_zoom,
+        i16
+    );
+    impl_local_value!(
+        set_style_transform_angle,
+        lv_obj_set_style_transform_angle,
+        i16
+    );
+    impl_local_value!(
+        set_style_transform_pivot_x,
+        lv_obj_set_style_transform_pivot_x,
+        i16
+    );
+    impl_local_value!(
+        set_style_transform_pivot_y,
+        lv_obj_set_style_transform_pivot_y,
+        i16
+    );
+    impl_local_value!(set_style_translate_x, lv_obj_set_style_translate_x, i16);
+    impl_local_value!(set_style_translate_y, lv_obj_set_style_translate_y, i16);
+
+    fn set_shadow(
+        &self,
+        width: i16,
+        x_ofs: i16,
+        y_ofs: i16,
+        spread: i16,
+        color: LvglColor,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into();
+        self.set_style_shadow_width(width, selector)
+            .set_style_shadow_ofs_x(x_ofs, selector)
+            .set_style_shadow_ofs_y(y_ofs, selector)
+            .set_style_shadow_spread(spread, selector)
+            .set_style_shadow_color(color, selector)
+    }
+
+    fn set_outline(
+        &self,
+        width: i16,
+        pad: i16,
+        color: LvglColor,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into();
+        self.set_style_outline_width(width, selector)
+            .set_style_outline_pad(pad, selector)
+            .set_style_outline_color(color, selector)
+    }
+
+    fn set_style_text_align(&self, align: LvglTextAlign, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_text_align(
+                self.get_handle(),
+                align.get_raw(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_style_text_decor(&self, decor: LvglTextDecor, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_text_decor(
+                self.get_handle(),
+                decor.get_raw(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_style_bg_img<T>(&self, image: T, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+        LvglPixmap: ImgToVoid<T>,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_bg_img_src(
+                self.get_handle(),
+                LvglPixmap::get_ref(image),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_transform(
+        &self,
+        zoom: i16,
+        angle: i16,
+        pivot_x: i16,
+        pivot_y: i16,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into();
+        self.set_style_transform_zoom(zoom, selector)
+            .set_style_transform_angle(angle, selector)
+            .set_style_transform_pivot_x(pivot_x, selector)
+            .set_style_transform_pivot_y(pivot_y, selector)
+    }
+
+    impl_local_color!(set_style_bg_grad_color, lv_obj_set_style_bg_grad_color);
+    impl_local_value!(set_style_bg_main_stop, lv_obj_set_style_bg_main_stop, i16);
+    impl_local_value!(set_style_bg_grad_stop, lv_obj_set_style_bg_grad_stop, i16);
+
+    fn set_style_bg_grad_dir(
+        &self,
+        direction: LvglGradDir,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_bg_grad_dir(
+                self.get_handle(),
+                direction.get_raw(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_style_bg_dither(&self, dither: LvglDither, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_bg_dither_mode(
+                self.get_handle(),
+                dither.get_raw(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_style_bg_grad(&self, gradient: &LvglGradient, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_bg_grad(
+                self.get_handle(),
+                gradient.get_handle(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    // two colors gradient on any part, LvglBar::set_gradient only targets indicator
+    fn set_style_gradient(
+        &self,
+        direction: LvglGradDir,
+        color: LvglColor,
+        grad_color: LvglColor,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        let selector = selector.into();
+        self.set_style_bg_opa(cglue::LV_OPA_COVER as u8, selector)
+            .set_style_bg_color(color, selector)
+            .set_style_bg_grad_color(grad_color, selector)
+            .set_style_bg_grad_dir(direction, selector)
+    }
+
+    // transition attached to PRESSED animates pressing, the one on DEFAULT animates release
+    fn set_transition(
+        &self,
+        transition: &LvglTransition,
+        selector: impl Into<LvglSelector>,
+    ) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_transition(
+                self.get_handle(),
+                transition.get_handle(),
+                selector.into().handle,
+            );
+        }
+        self
+    }
+
+    fn set_style_text_font(&self, font: &LvglFont, selector: impl Into<LvglSelector>) -> &Self
+    where
+        Self: LvglCommon,
+    {
+        unsafe {
+            cglue::lv_obj_set_style_text_font(
+                self.get_handle(),
+                font as *const _ as *const cglue::lv_font_t,
+                selector.into().handle,
+            );
         }
         self
     }
@@ -262,6 +709,14 @@ impl LvglHandle {
         unsafe {
             cglue::lv_init();
 
+            // image decoders are opt-in, each feature requires matching LV_USE_xxx within lv_conf.h
+            #[cfg(feature = "png")]
+            cglue::lv_png_init();
+            #[cfg(feature = "bmp")]
+            cglue::lv_bmp_init();
+            #[cfg(feature = "sjpg")]
+            cglue::lv_split_jpeg_init();
+
             #[cfg(not(use_gtk))]
             {
                 cglue::fbdev_init();
@@ -328,38 +783,49 @@ impl LvglHandle {
         }
     }
 
+    // widget paths default to 'L:' drive, serve it from 'root' when lv_conf does not provide it
+    pub fn set_fs_root(&self, root: &str) -> Result<&Self, LvglError> {
+        LvglFsDriver::register('L', root)?;
+        Ok(self)
+    }
+
     pub fn set_theme(
         &mut self,
         primary: LvglColor,
         secondary: LvglColor,
         dark: bool,
-        font: &LvglFont,
+        font: &'static LvglFont,
     ) -> &Self {
+        // display background is not part of lvgl themes, keep historical grey
+        self.set_display_bg(LvglColor::rvb(100, 100, 100), 128);
+        LvglTheme::new_default(primary, secondary, dark, font).activate();
+        /*
+        let cursor_handle = cglue::lv_img_create(cglue::lv_scr_action());
+        cglue::lv_img_set_src(
+            cursor_handle,
+            &cglue::lv_mouse_cursor as *const _ as *const raw::c_void,
+        );
+        cglue::lv_indev_set_cursor(self._mouse_handle, cursor_handle);
+        */
+        self
+    }
+
+    // color visible behind transparent screens
+    pub fn set_display_bg(&self, color: LvglColor, opacity: u8) -> &Self {
         unsafe {
             let display = cglue::lv_disp_get_default();
-            cglue::lv_disp_set_bg_color(display, cglue::lv_color_mk(100, 100, 100));
-            cglue::lv_disp_set_bg_opa(display, 128);
-
-            let theme = cglue::lv_theme_default_init(
-                display,
-                primary.handle,
-                secondary.handle,
-                dark,
-                font as *const _ as *const cglue::lv_font_t,
-            );
-            cglue::lv_disp_set_theme(display as *mut cglue::_lv_disp_t, theme);
-            /*
-            let cursor_handle = cglue::lv_img_create(cglue::lv_scr_action());
-            cglue::lv_img_set_src(
-                cursor_handle,
-                &cglue::lv_mouse_cursor as *const _ as *const raw::c_void,
-            );
-            cglue::lv_indev_set_cursor(self._mouse_handle, cursor_handle);
-            */
+            cglue::lv_disp_set_bg_color(display, color.handle);
+            cglue::lv_disp_set_bg_opa(display, opacity);
         }
         self
     }
 
+    // switch theme at runtime, existing widgets are restyled
+    pub fn apply_theme(&self, theme: &'static LvglTheme) -> &Self {
+        theme.activate();
+        self
+    }
+
     pub fn get_root_widget(&self) -> &'static LvglWidget {
         &LvglWidget::Display()
     }
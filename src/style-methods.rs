@@ -0,0 +1,385 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::prelude::*;
+use std::cell::Cell;
+use std::mem;
+
+macro_rules! impl_style_color {
+    ($method:ident, $setter:ident) => {
+        pub fn $method(&self, color: LvglColor) -> &Self {
+            unsafe { cglue::$setter(self.handle, color.handle) };
+            self.refresh()
+        }
+    };
+}
+
+macro_rules! impl_style_value {
+    ($method:ident, $setter:ident, $type:ty) => {
+        pub fn $method(&self, value: $type) -> &Self {
+            unsafe { cglue::$setter(self.handle, value) };
+            self.refresh()
+        }
+    };
+}
+
+// lv_style_t shared by many widgets, any change is reported to every object using it
+pub struct LvglStyle {
+    handle: *mut cglue::lv_style_t,
+    attached: Cell<bool>,
+}
+
+impl LvglStyle {
+    pub fn new() -> &'static Self {
+        unsafe {
+            let handle = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_t>()));
+            cglue::lv_style_init(handle);
+            Box::leak(Box::new(LvglStyle {
+                handle,
+                attached: Cell::new(false),
+            }))
+        }
+    }
+
+    pub fn get_handle(&self) -> *mut cglue::lv_style_t {
+        self.handle
+    }
+
+    // avoid scanning every object while style is still under construction
+    fn refresh(&self) -> &Self {
+        if self.attached.get() {
+            unsafe { cglue::lv_obj_report_style_change(self.handle) };
+        }
+        self
+    }
+
+    pub(crate) fn set_attached(&self) {
+        self.attached.set(true);
+    }
+
+    // remove every property, widgets fallback to theme
+    pub fn reset(&self) -> &Self {
+        unsafe {
+            cglue::lv_style_reset(self.handle);
+            cglue::lv_style_init(self.handle);
+        }
+        self.refresh()
+    }
+
+    // size & position
+    impl_style_value!(set_width, lv_style_set_width, i16);
+    impl_style_value!(set_min_width, lv_style_set_min_width, i16);
+    impl_style_value!(set_max_width, lv_style_set_max_width, i16);
+    impl_style_value!(set_height, lv_style_set_height, i16);
+    impl_style_value!(set_min_height, lv_style_set_min_height, i16);
+    impl_style_value!(set_max_height, lv_style_set_max_height, i16);
+    impl_style_value!(set_x, lv_style_set_x, i16);
+    impl_style_value!(set_y, lv_style_set_y, i16);
+
+    pub fn set_size(&self, width: i16, height: i16) -> &Self {
+        self.set_width(width).set_height(height)
+    }
+
+    // padding
+    impl_style_value!(set_pad_top, lv_style_set_pad_top, i16);
+    impl_style_value!(set_pad_bottom, lv_style_set_pad_bottom, i16);
+    impl_style_value!(set_pad_left, lv_style_set_pad_left, i16);
+    impl_style_value!(set_pad_right, lv_style_set_pad_right, i16);
+    impl_style_value!(set_pad_row, lv_style_set_pad_row, i16);
+    impl_style_value!(set_pad_column, lv_style_set_pad_column, i16);
+
+    pub fn set_padding(&self, top: i16, botton: i16, right: i16, left: i16) -> &Self {
+        self.set_pad_top(top)
+            .set_pad_bottom(botton)
+            .set_pad_right(right)
+            .set_pad_left(left)
+    }
+
+    // background
+    impl_style_color!(set_bg_color, lv_style_set_bg_color);
+    impl_style_value!(set_bg_opa, lv_style_set_bg_opa, u8);
+
+    pub fn set_background(&self, color: LvglColor, opacity: u8) -> &Self {
+        self.set_bg_color(color).set_bg_opa(opacity)
+    }
+
+    // border
+    impl_style_color!(set_border_color, lv_style_set_border_color);
+    impl_style_value!(set_border_opa, lv_style_set_border_opa, u8);
+    impl_style_value!(set_border_width, lv_style_set_border_width, i16);
+    impl_style_value!(set_border_post, lv_style_set_border_post, bool);
+
+    pub fn set_border(&self, width: i16, color: LvglColor) -> &Self {
+        self.set_border_width(width).set_border_color(color)
+    }
+
+    pub fn set_border_side(&self, sides: &[LvglDirection]) -> &Self {
+        // LV_BORDER_SIDE_xxx & LV_DIR_xxx do not share the same bit mask
+        let mut mask = cglue::LV_BORDER_SIDE_NONE;
+        for side in sides {
+            mask |= match side {
+                LvglDirection::TOP => cglue::LV_BORDER_SIDE_TOP,
+                LvglDirection::BOTTOM => cglue::LV_BORDER_SIDE_BOTTOM,
+                LvglDirection::LEFT => cglue::LV_BORDER_SIDE_LEFT,
+                LvglDirection::RIGHT => cglue::LV_BORDER_SIDE_RIGHT,
+                LvglDirection::HOR => cglue::LV_BORDER_SIDE_LEFT | cglue::LV_BORDER_SIDE_RIGHT,
+                LvglDirection::VER => cglue::LV_BORDER_SIDE_TOP | cglue::LV_BORDER_SIDE_BOTTOM,
+                LvglDirection::ALL => cglue::LV_BORDER_SIDE_FULL,
+                LvglDirection::NONE => cglue::LV_BORDER_SIDE_NONE,
+            };
+        }
+        unsafe { cglue::lv_style_set_border_side(self.handle, mask as u8) };
+        self.refresh()
+    }
+
+    // text
+    impl_style_color!(set_text_color, lv_style_set_text_color);
+    impl_style_value!(set_text_opa, lv_style_set_text_opa, u8);
+
+    pub fn set_text_font(&self, font: &LvglFont) -> &Self {
+        unsafe {
+            cglue::lv_style_set_text_font(self.handle, font as *const _ as *const cglue::lv_font_t)
+        };
+        self.refresh()
+    }
+
+    // line & arc
+    impl_style_color!(set_line_color, lv_style_set_line_color);
+    impl_style_value!(set_line_opa, lv_style_set_line_opa, u8);
+    impl_style_value!(set_line_width, lv_style_set_line_width, i16);
+    impl_style_value!(set_line_rounded, lv_style_set_line_rounded, bool);
+    impl_style_value!(set_line_dash_width, lv_style_set_line_dash_width, i16);
+    impl_style_value!(set_line_dash_gap, lv_style_set_line_dash_gap, i16);
+    impl_style_color!(set_arc_color, lv_style_set_arc_color);
+    impl_style_value!(set_arc_opa, lv_style_set_arc_opa, u8);
+    impl_style_value!(set_arc_width, lv_style_set_arc_width, i16);
+    impl_style_value!(set_arc_rounded, lv_style_set_arc_rounded, bool);
+
+    // image
+    impl_style_color!(set_img_recolor, lv_style_set_img_recolor);
+    impl_style_value!(set_img_recolor_opa, lv_style_set_img_recolor_opa, u8);
+    impl_style_value!(set_img_opa, lv_style_set_img_opa, u8);
+
+    // geometry & opacity
+    impl_style_value!(set_radius, lv_style_set_radius, i16);
+    impl_style_value!(set_opa, lv_style_set_opa, u8);
+    impl_style_value!(set_clip_corner, lv_style_set_clip_corner, bool);
+
+    pub fn set_circle(&self) -> &Self {
+        self.set_radius(cglue::LV_RADIUS_CIRCLE as i16)
+    }
+
+    // shadow
+    impl_style_color!(set_shadow_color, lv_style_set_shadow_color);
+    impl_style_value!(set_shadow_opa, lv_style_set_shadow_opa, u8);
+    impl_style_value!(set_shadow_width, lv_style_set_shadow_width, i16);
+    impl_style_value!(set_shadow_ofs_x, lv_style_set_shadow_ofs_x, i16);
+    impl_style_value!(set_shadow_ofs_y, lv_style_set_shadow_ofs_y, i16);
+    impl_style_value!(set_shadow_spread, lv_style_set_shadow_spread, i16);
+
+    pub fn set_shadow(
+        &self,
+        width: i16,
+        x_ofs: i16,
+        y_ofs: i16,
+        spread: i16,
+        color: LvglColor,
+    ) -> &Self {
+        self.set_shadow_width(width)
+            .set_shadow_ofs_x(x_ofs)
+            .set_shadow_ofs_y(y_ofs)
+            .set_shadow_spread(spread)
+            .set_shadow_color(color)
+    }
+
+    // outline
+    impl_style_color!(set_outline_color, lv_style_set_outline_color);
+    impl_style_value!(set_outline_opa, lv_style_set_outline_opa, u8);
+    impl_style_value!(set_outline_width, lv_style_set_outline_width, i16);
+    impl_style_value!(set_outline_pad, lv_style_set_outline_pad, i16);
+
+    pub fn set_outline(&self, width: i16, pad: i16, color: LvglColor) -> &Self {
+        self.set_outline_width(width)
+            .set_outline_pad(pad)
+            .set_outline_color(color)
+    }
+
+    // text layout
+    impl_style_value!(set_text_letter_space, lv_style_set_text_letter_space, i16);
+    impl_style_value!(set_text_line_space, lv_style_set_text_line_space, i16);
+
+    pub fn set_text_align(&self, align: LvglTextAlign) -> &Self {
+        unsafe { cglue::lv_style_set_text_align(self.handle, align.get_raw()) };
+        self.refresh()
+    }
+
+    pub fn set_text_decor(&self, decor: LvglTextDecor) -> &Self {
+        unsafe { cglue::lv_style_set_text_decor(self.handle, decor.get_raw()) };
+        self.refresh()
+    }
+
+    // background image, symbol or static image descriptor
+    impl_style_value!(set_bg_img_opa, lv_style_set_bg_img_opa, u8);
+    impl_style_color!(set_bg_img_recolor, lv_style_set_bg_img_recolor);
+    impl_style_value!(set_bg_img_recolor_opa, lv_style_set_bg_img_recolor_opa, u8);
+    impl_style_value!(set_bg_img_tiled, lv_style_set_bg_img_tiled, bool);
+
+    pub fn set_bg_img<T>(&self, image: T) -> &Self
+    where
+        LvglPixmap: ImgToVoid<T>,
+    {
+        unsafe { cglue::lv_style_set_bg_img_src(self.handle, LvglPixmap::get_ref(image)) };
+        self.refresh()
+    }
+
+    // transform, angle in 0.1 degree, zoom 256 = 100%
+    impl_style_value!(set_transform_width, lv_style_set_transform_width, i16);
+    impl_style_value!(set_transform_height, lv_style_set_transform_height, i16);
+    impl_style_value!(set_transform_zoom, lv_style_set_transform_zoom, i16);
+    impl_style_value!(set_transform_angle, lv_style_set_transform_angle, i16);
+    impl_style_value!(set_transform_pivot_x, lv_style_set_transform_pivot_x, i16);
+    impl_style_value!(set_transform_pivot_y, lv_style_set_transform_pivot_y, i16);
+    impl_style_value!(set_translate_x, lv_style_set_translate_x, i16);
+    impl_style_value!(set_translate_y, lv_style_set_translate_y, i16);
+
+    pub fn set_transform(&self, zoom: i16, angle: i16, pivot_x: i16, pivot_y: i16) -> &Self {
+        self.set_transform_zoom(zoom)
+            .set_transform_angle(angle)
+            .set_transform_pivot_x(pivot_x)
+            .set_transform_pivot_y(pivot_y)
+    }
+
+    // simple two colors gradient, stops are 0..255 fractions of widget size
+    impl_style_color!(set_bg_grad_color, lv_style_set_bg_grad_color);
+    impl_style_value!(set_bg_main_stop, lv_style_set_bg_main_stop, i16);
+    impl_style_value!(set_bg_grad_stop, lv_style_set_bg_grad_stop, i16);
+
+    pub fn set_bg_grad_dir(&self, direction: LvglGradDir) -> &Self {
+        unsafe { cglue::lv_style_set_bg_grad_dir(self.handle, direction.get_raw()) };
+        self.refresh()
+    }
+
+    pub fn set_bg_dither(&self, dither: LvglDither) -> &Self {
+        unsafe { cglue::lv_style_set_bg_dither_mode(self.handle, dither.get_raw()) };
+        self.refresh()
+    }
+
+    pub fn set_gradient(
+        &self,
+        direction: LvglGradDir,
+        color: LvglColor,
+        grad_color: LvglColor,
+        main_stop: i16,
+        grad_stop: i16,
+    ) -> &Self {
+        self.set_bg_color(color)
+            .set_bg_grad_color(grad_color)
+            .set_bg_main_stop(main_stop)
+            .set_bg_grad_stop(grad_stop)
+            .set_bg_grad_dir(direction)
+    }
+
+    // multi stops gradient, takes precedence over simple gradient properties
+    pub fn set_bg_grad(&self, gradient: &LvglGradient) -> &Self {
+        unsafe { cglue::lv_style_set_bg_grad(self.handle, gradient.get_handle()) };
+        self.refresh()
+    }
+
+    // used when entering the state this style is attached to
+    pub fn set_transition(&self, transition: &LvglTransition) -> &Self {
+        unsafe { cglue::lv_style_set_transition(self.handle, transition.get_handle()) };
+        self.refresh()
+    }
+}
+
+// properties animated when a widget enters the state holding the transition
+pub struct LvglTransition {
+    handle: *mut cglue::lv_style_transition_dsc_t,
+}
+
+impl LvglTransition {
+    pub fn new(
+        props: &[LvglStyleProp],
+        duration: u32,
+        delay: u32,
+        easing: LvglEasing,
+    ) -> &'static Self {
+        // lvgl expects a zero terminated property list living as long as the transition
+        let mut raw_props: Vec<cglue::lv_style_prop_t> =
+            props.iter().map(|prop| prop.get_raw()).collect();
+        raw_props.push(cglue::LV_STYLE_PROP_INV as cglue::lv_style_prop_t);
+        let raw_props = raw_props.leak();
+
+        unsafe {
+            let handle = Box::leak(Box::new(mem::zeroed::<cglue::lv_style_transition_dsc_t>()));
+            cglue::lv_style_transition_dsc_init(
+                handle,
+                raw_props.as_ptr(),
+                easing.get_path(),
+                duration,
+                delay,
+                std::ptr::null_mut(),
+            );
+            Box::leak(Box::new(LvglTransition { handle }))
+        }
+    }
+
+    pub fn get_handle(&self) -> *const cglue::lv_style_transition_dsc_t {
+        self.handle
+    }
+}
+
+// lv_grad_dsc_t, number of stops is limited by LV_GRADIENT_MAX_STOPS from lv_conf.h
+pub struct LvglGradient {
+    handle: *mut cglue::lv_grad_dsc_t,
+}
+
+impl LvglGradient {
+    pub fn new(
+        direction: LvglGradDir,
+        stops: &[(LvglColor, u8)],
+    ) -> Result<&'static Self, LvglError> {
+        if stops.len() < 2 || stops.len() > cglue::LV_GRADIENT_MAX_STOPS as usize {
+            return Err(LvglError::Unsupported(format!(
+                "gradient stops count:{} should be within 2..{}",
+                stops.len(),
+                cglue::LV_GRADIENT_MAX_STOPS
+            )));
+        }
+
+        unsafe {
+            let handle = Box::leak(Box::new(mem::zeroed::<cglue::lv_grad_dsc_t>()));
+            for (idx, (color, frac)) in stops.iter().enumerate() {
+                handle.stops[idx].color = color.handle;
+                handle.stops[idx].frac = *frac;
+            }
+            handle.stops_count = stops.len() as u8;
+            handle.set_dir(direction.get_raw());
+            handle.set_dither(cglue::LV_DITHER_NONE as u8);
+            Ok(Box::leak(Box::new(LvglGradient { handle })))
+        }
+    }
+
+    // set before attaching gradient to a style, rendered gradients are cached by lvgl
+    pub fn set_dither(&self, dither: LvglDither) -> &Self {
+        unsafe { (*self.handle).set_dither(dither.get_raw()) };
+        self
+    }
+
+    pub fn get_handle(&self) -> *const cglue::lv_grad_dsc_t {
+        self.handle
+    }
+}
@@ -0,0 +1,449 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+// Style sheet format (toml, json uses the same layout)
+//
+//   [palette]
+//   brand = "#4A148C"
+//   accent = "AMBER"
+//
+//   [class.button]
+//   bg_color = "brand"
+//   radius = 12
+//   text_font = "std_18"
+//
+use crate::prelude::*;
+use serde_json::{Map, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fs;
+use std::time::SystemTime;
+
+pub struct LvglStyleSheet {
+    path: String,
+    modified: Cell<Option<SystemTime>>,
+    classes: RefCell<HashMap<String, &'static LvglStyle>>,
+    #[cfg(debug_assertions)]
+    on_error: RefCell<Option<Box<dyn Fn(&LvglError)>>>,
+}
+
+// properties are validated for the whole document before any style is touched
+type LvglSheetSetter = Box<dyn FnOnce(&LvglStyle)>;
+
+macro_rules! sheet_setter {
+    ($method:ident, $value:expr) => {{
+        let value = $value;
+        let setter: LvglSheetSetter = Box::new(move |style| {
+            style.$method(value);
+        });
+        setter
+    }};
+}
+
+fn sheet_error(class: &str, key: &str, value: &Value) -> LvglError {
+    LvglError::StyleSheet(format!("class:{} invalid value {}={}", class, key, value))
+}
+
+// palette variable, hex value or lvgl palette name
+fn get_color(palette: &Map<String, Value>, value: &Value) -> Option<LvglColor> {
+    let mut name = value.as_str()?;
+    if let Some(variable) = palette.get(name) {
+        name = variable.as_str()?;
+    }
+    LvglColor::from_hex(name).or_else(|| LvglColor::from_name(name))
+}
+
+// 0..255 or percentage "50%"
+fn get_opa(value: &Value) -> Option<u8> {
+    match value {
+        Value::String(text) => {
+            let percent = text.strip_suffix('%')?.trim().parse::<u32>().ok()?;
+            Some((percent.min(100) * 255 / 100) as u8)
+        }
+        _ => u8::try_from(value.as_u64()?).ok(),
+    }
+}
+
+fn get_coord(value: &Value) -> Option<i16> {
+    i16::try_from(value.as_i64()?).ok()
+}
+
+fn parse_property(
+    palette: &Map<String, Value>,
+    key: &str,
+    value: &Value,
+) -> Option<LvglSheetSetter> {
+    let setter = match key {
+        "bg_color" => sheet_setter!(set_bg_color, get_color(palette, value)?),
+        "bg_grad_color" => sheet_setter!(set_bg_grad_color, get_color(palette, value)?),
+        "border_color" => sheet_setter!(set_border_color, get_color(palette, value)?),
+        "text_color" => sheet_setter!(set_text_color, get_color(palette, value)?),
+        "shadow_color" => sheet_setter!(set_shadow_color, get_color(palette, value)?),
+        "outline_color" => sheet_setter!(set_outline_color, get_color(palette, value)?),
+        "line_color" => sheet_setter!(set_line_color, get_color(palette, value)?),
+        "arc_color" => sheet_setter!(set_arc_color, get_color(palette, value)?),
+        "img_recolor" => sheet_setter!(set_img_recolor, get_color(palette, value)?),
+
+        "opa" => sheet_setter!(set_opa, get_opa(value)?),
+        "bg_opa" => sheet_setter!(set_bg_opa, get_opa(value)?),
+        "border_opa" => sheet_setter!(set_border_opa, get_opa(value)?),
+        "text_opa" => sheet_setter!(set_text_opa, get_opa(value)?),
+        "shadow_opa" => sheet_setter!(set_shadow_opa, get_opa(value)?),
+        "outline_opa" => sheet_setter!(set_outline_opa, get_opa(value)?),
+        "line_opa" => sheet_setter!(set_line_opa, get_opa(value)?),
+        "arc_opa" => sheet_setter!(set_arc_opa, get_opa(value)?),
+        "img_opa" => sheet_setter!(set_img_opa, get_opa(value)?),
+        "img_recolor_opa" => sheet_setter!(set_img_recolor_opa, get_opa(value)?),
+
+        "width" => sheet_setter!(set_width, get_coord(value)?),
+        "height" => sheet_setter!(set_height, get_coord(value)?),
+        "min_width" => sheet_setter!(set_min_width, get_coord(value)?),
+        "max_width" => sheet_setter!(set_max_width, get_coord(value)?),
+        "min_height" => sheet_setter!(set_min_height, get_coord(value)?),
+        "max_height" => sheet_setter!(set_max_height, get_coord(value)?),
+        "radius" => sheet_setter!(set_radius, get_coord(value)?),
+        "pad_top" => sheet_setter!(set_pad_top, get_coord(value)?),
+        "pad_bottom" => sheet_setter!(set_pad_bottom, get_coord(value)?),
+        "pad_left" => sheet_setter!(set_pad_left, get_coord(value)?),
+        "pad_right" => sheet_setter!(set_pad_right, get_coord(value)?),
+        "pad_row" => sheet_setter!(set_pad_row, get_coord(value)?),
+        "pad_column" => sheet_setter!(set_pad_column, get_coord(value)?),
+        "pad_all" => {
+            let pad = get_coord(value)?;
+            let setter: LvglSheetSetter = Box::new(move |style| {
+                style.set_padding(pad, pad, pad, pad);
+            });
+            setter
+        }
+        "border_width" => sheet_setter!(set_border_width, get_coord(value)?),
+        "shadow_width" => sheet_setter!(set_shadow_width, get_coord(value)?),
+        "shadow_ofs_x" => sheet_setter!(set_shadow_ofs_x, get_coord(value)?),
+        "shadow_ofs_y" => sheet_setter!(set_shadow_ofs_y, get_coord(value)?),
+        "shadow_spread" => sheet_setter!(set_shadow_spread, get_coord(value)?),
+        "outline_width" => sheet_setter!(set_outline_width, get_coord(value)?),
+        "outline_pad" => sheet_setter!(set_outline_pad, get_coord(value)?),
+        "line_width" => sheet_setter!(set_line_width, get_coord(value)?),
+        "arc_width" => sheet_setter!(set_arc_width, get_coord(value)?),
+        "text_letter_space" => sheet_setter!(set_text_letter_space, get_coord(value)?),
+        "text_line_space" => sheet_setter!(set_text_line_space, get_coord(value)?),
+        "bg_main_stop" => sheet_setter!(set_bg_main_stop, get_coord(value)?),
+        "bg_grad_stop" => sheet_setter!(set_bg_grad_stop, get_coord(value)?),
+        "transform_zoom" => sheet_setter!(set_transform_zoom, get_coord(value)?),
+        "transform_angle" => sheet_setter!(set_transform_angle, get_coord(value)?),
+
+        "clip_corner" => sheet_setter!(set_clip_corner, value.as_bool()?),
+        "border_post" => sheet_setter!(set_border_post, value.as_bool()?),
+        "line_rounded" => sheet_setter!(set_line_rounded, value.as_bool()?),
+        "arc_rounded" => sheet_setter!(set_arc_rounded, value.as_bool()?),
+
+        "text_font" => {
+            let name = value.as_str()?;
+            let font = match LvglMkFont::from_name(name) {
+                Some(font) => font,
+                None => LvglMkFont::from_file(name).ok()?,
+            };
+            sheet_setter!(set_text_font, font)
+        }
+        "text_align" => sheet_setter!(
+            set_text_align,
+            match value.as_str()? {
+                "auto" => LvglTextAlign::AUTO,
+                "left" => LvglTextAlign::LEFT,
+                "center" => LvglTextAlign::CENTER,
+                "right" => LvglTextAlign::RIGHT,
+                _ => return None,
+            }
+        ),
+        "text_decor" => sheet_setter!(
+            set_text_decor,
+            match value.as_str()? {
+                "none" => LvglTextDecor::NONE,
+                "underline" => LvglTextDecor::UNDERLINE,
+                "strikethrough" => LvglTextDecor::STRIKETHROUGH,
+                _ => return None,
+            }
+        ),
+        "bg_grad_dir" => sheet_setter!(
+            set_bg_grad_dir,
+            match value.as_str()? {
+                "none" => LvglGradDir::NONE,
+                "ver" => LvglGradDir::VER,
+                "hor" => LvglGradDir::HOR,
+                _ => return None,
+            }
+        ),
+        _ => return None,
+    };
+    Some(setter)
+}
+
+// reload style sheet when file changes
+#[cfg(debug_assertions)]
+unsafe extern "C" fn sheet_watch_cb(timer: *mut cglue::lv_timer_t) {
+    let sheet = &*((*timer).user_data as *const LvglStyleSheet);
+    let modified = fs::metadata(&sheet.path)
+        .and_then(|meta| meta.modified())
+        .ok();
+    if modified.is_some() && modified != sheet.modified.get() {
+        if let Err(error) = sheet.reload() {
+            if let Some(on_error) = &*sheet.on_error.borrow() {
+                on_error(&error);
+            }
+        }
+    }
+}
+
+impl LvglStyleSheet {
+    // file extension selects json, any other is parsed as toml
+    pub fn load(path: &str) -> Result<&'static Self, LvglError> {
+        let sheet = Box::leak(Box::new(LvglStyleSheet {
+            path: path.to_string(),
+            modified: Cell::new(None),
+            classes: RefCell::new(HashMap::new()),
+            #[cfg(debug_assertions)]
+            on_error: RefCell::new(None),
+        }));
+        sheet.reload()?;
+        Ok(sheet)
+    }
+
+    // styles are updated in place, widgets using a class are refreshed
+    pub fn reload(&self) -> Result<&Self, LvglError> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(value) => value,
+            Err(error) => return Err(LvglError::Filesystem(format!("{}: {}", self.path, error))),
+        };
+        self.modified.set(
+            fs::metadata(&self.path)
+                .and_then(|meta| meta.modified())
+                .ok(),
+        );
+
+        let root: Value = if self.path.ends_with(".json") {
+            serde_json::from_str(&text)
+                .map_err(|error| LvglError::StyleSheet(format!("{}: {}", self.path, error)))?
+        } else {
+            toml::from_str(&text)
+                .map_err(|error| LvglError::StyleSheet(format!("{}: {}", self.path, error)))?
+        };
+        self.parse(&root)?;
+        Ok(self)
+    }
+
+    fn parse(&self, root: &Value) -> Result<(), LvglError> {
+        let empty = Map::new();
+        let palette = match root.get("palette") {
+            None => &empty,
+            Some(value) => value
+                .as_object()
+                .ok_or_else(|| LvglError::StyleSheet("palette should be a table".to_string()))?,
+        };
+        let classes = match root.get("class").and_then(|value| value.as_object()) {
+            Some(value) => value,
+            None => {
+                return Err(LvglError::StyleSheet(format!(
+                    "{}: no [class] defined",
+                    self.path
+                )))
+            }
+        };
+
+        let mut parsed = Vec::new();
+        for (name, properties) in classes {
+            let properties = match properties.as_object() {
+                Some(value) => value,
+                None => return Err(sheet_error(name, "class", properties)),
+            };
+            let mut setters = Vec::new();
+            for (key, value) in properties {
+                match parse_property(palette, key, value) {
+                    Some(setter) => setters.push(setter),
+                    None => return Err(sheet_error(name, key, value)),
+                }
+            }
+            parsed.push((name.to_string(), setters));
+        }
+
+        // document is valid, classes removed from file are emptied and forgotten
+        let mut known = self.classes.borrow_mut();
+        known.retain(|name, style| {
+            let keep = parsed.iter().any(|(parsed, _)| parsed == name);
+            if !keep {
+                style.reset();
+            }
+            keep
+        });
+        for (name, setters) in parsed {
+            let style = *known.entry(name).or_insert_with(LvglStyle::new);
+            style.reset();
+            for setter in setters {
+                setter(style);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_class(&self, name: &str) -> Result<&'static LvglStyle, LvglError> {
+        match self.classes.borrow().get(name) {
+            Some(style) => Ok(style),
+            None => Err(LvglError::StyleSheet(format!(
+                "{}: unknown class:{}",
+                self.path, name
+            ))),
+        }
+    }
+
+    pub fn get_classes(&self) -> Vec<String> {
+        self.classes.borrow().keys().cloned().collect()
+    }
+
+    // development helper, poll file modification time and reload, invalid sheet keeps
+    // previous styles and is reported to on_error
+    #[cfg(debug_assertions)]
+    pub fn watch<F>(&'static self, period: u32, on_error: F) -> &Self
+    where
+        F: Fn(&LvglError) + 'static,
+    {
+        *self.on_error.borrow_mut() = Some(Box::new(on_error));
+        unsafe {
+            cglue::lv_timer_create(
+                Some(sheet_watch_cb),
+                period,
+                self as *const _ as *mut std::os::raw::c_void,
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem;
+    use std::sync::Mutex;
+
+    // lvgl memory allocator is not thread safe, serialize tests creating styles
+    static LVGL: Mutex<()> = Mutex::new(());
+
+    fn new_sheet() -> LvglStyleSheet {
+        unsafe { cglue::lv_init() };
+        LvglStyleSheet {
+            path: "test.toml".to_string(),
+            modified: Cell::new(None),
+            classes: RefCell::new(HashMap::new()),
+            #[cfg(debug_assertions)]
+            on_error: RefCell::new(None),
+        }
+    }
+
+    fn parse(sheet: &LvglStyleSheet, text: &str) -> Result<(), LvglError> {
+        sheet.parse(&toml::from_str::<Value>(text).unwrap())
+    }
+
+    fn get_radius(style: &LvglStyle) -> Option<i32> {
+        unsafe {
+            let mut value = mem::zeroed::<cglue::lv_style_value_t>();
+            let found = cglue::lv_style_get_prop(
+                style.get_handle(),
+                LvglStyleProp::RADIUS.get_raw(),
+                &mut value,
+            );
+            if found == cglue::LV_STYLE_RES_FOUND as u8 {
+                Some(value.num)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn opa_values() {
+        assert_eq!(get_opa(&Value::from(128)), Some(128));
+        assert_eq!(get_opa(&Value::from(256)), None);
+        assert_eq!(get_opa(&Value::from(-1)), None);
+        assert_eq!(get_opa(&Value::from("100%")), Some(255));
+        assert_eq!(get_opa(&Value::from("50%")), Some(127));
+        assert_eq!(get_opa(&Value::from("150%")), Some(255));
+        assert_eq!(get_opa(&Value::from("50")), None);
+    }
+
+    #[test]
+    fn color_values() {
+        let mut palette = Map::new();
+        palette.insert("brand".to_string(), Value::from("#4A148C"));
+        palette.insert("accent".to_string(), Value::from("AMBER"));
+
+        let color = |value: &str| get_color(&palette, &Value::from(value)).map(|c| c.handle.full);
+        let brand = LvglColor::rvb(0x4A, 0x14, 0x8C).handle.full;
+        assert_eq!(color("brand"), Some(brand));
+        assert_eq!(color("#4A148C"), Some(brand));
+        assert_eq!(color("#FFF"), color("#FFFFFF"));
+        assert_eq!(color("accent"), Some(LvglColor::AMBER().handle.full));
+        assert_eq!(color("AMBER"), Some(LvglColor::AMBER().handle.full));
+        assert_eq!(color("#12345"), None);
+        assert_eq!(color("not-a-color"), None);
+        assert!(get_color(&palette, &Value::from(12)).is_none());
+    }
+
+    #[test]
+    fn property_values() {
+        let palette = Map::new();
+        assert!(parse_property(&palette, "radius", &Value::from(12)).is_some());
+        assert!(parse_property(&palette, "radius", &Value::from(40000)).is_none());
+        assert!(parse_property(&palette, "text_align", &Value::from("center")).is_some());
+        assert!(parse_property(&palette, "text_align", &Value::from("middle")).is_none());
+        assert!(parse_property(&palette, "clip_corner", &Value::from(1)).is_none());
+        assert!(parse_property(&palette, "unknown_key", &Value::from(1)).is_none());
+    }
+
+    #[test]
+    fn invalid_document_keeps_styles() {
+        let _lock = LVGL.lock().unwrap();
+        let sheet = new_sheet();
+        parse(&sheet, "[class.button]\nradius = 4\n").unwrap();
+        let button = sheet.get_class("button").unwrap();
+
+        // second class is invalid, first one should not be updated nor created
+        let result = parse(
+            &sheet,
+            "[class.button]\nradius = 8\n[class.label]\nbg_opa = \"half\"\n",
+        );
+        assert!(matches!(result, Err(LvglError::StyleSheet(_))));
+        assert_eq!(get_radius(button), Some(4));
+        assert!(sheet.get_class("label").is_err());
+
+        assert!(parse(&sheet, "[palette]\nbrand = \"#000\"\n").is_err());
+    }
+
+    #[test]
+    fn removed_classes_are_dropped() {
+        let _lock = LVGL.lock().unwrap();
+        let sheet = new_sheet();
+        parse(
+            &sheet,
+            "[class.button]\nradius = 4\n[class.label]\nradius = 2\n",
+        )
+        .unwrap();
+        let label = sheet.get_class("label").unwrap();
+
+        parse(&sheet, "[class.button]\nradius = 6\n").unwrap();
+        assert_eq!(sheet.get_classes(), vec!["button".to_string()]);
+        assert_eq!(get_radius(sheet.get_class("button").unwrap()), Some(6));
+        // widgets still using removed class fallback to theme
+        assert_eq!(get_radius(label), None);
+    }
+}
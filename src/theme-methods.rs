@@ -0,0 +1,283 @@
+/*
+ * Copyright (C) 2015-2022 IoT.bzh Company
+ * Author: Fulup Ar Foll <fulup@iot.bzh>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+use crate::prelude::*;
+use std::cell::RefCell;
+use std::mem;
+use std::os::raw;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+// theme currently attached to default display
+static ACTIVE_THEME: AtomicPtr<LvglTheme> = AtomicPtr::new(std::ptr::null_mut());
+
+// lvgl default theme is a singleton, its configuration is replayed at activation
+struct LvglThemeConfig {
+    primary: LvglColor,
+    secondary: LvglColor,
+    dark: bool,
+    font: &'static LvglFont,
+}
+
+type LvglThemeApply = Box<dyn Fn(&LvglThemeTarget)>;
+
+// object being styled by a custom theme
+pub struct LvglThemeTarget {
+    handle: *mut cglue::lv_obj_t,
+    theme: &'static LvglTheme,
+}
+
+impl LvglThemeTarget {
+    pub fn get_handle(&self) -> *mut cglue::lv_obj_t {
+        self.handle
+    }
+
+    pub fn is_class(&self, class: LvglClass) -> bool {
+        unsafe { cglue::lv_obj_check_type(self.handle, class.get_raw()) }
+    }
+
+    pub fn has_class(&self, class: LvglClass) -> bool {
+        unsafe { cglue::lv_obj_has_class(self.handle, class.get_raw()) }
+    }
+
+    // styles are tracked to be removed when switching to another theme
+    pub fn add_style(&self, style: &'static LvglStyle, selector: impl Into<LvglSelector>) -> &Self {
+        style.set_attached();
+        unsafe {
+            cglue::lv_obj_add_style(self.handle, style.get_handle(), selector.into().handle);
+        }
+        let mut styles = self.theme.styles.borrow_mut();
+        if !styles.iter().any(|known| std::ptr::eq(*known, style)) {
+            styles.push(style);
+        }
+        self
+    }
+}
+
+pub struct LvglTheme {
+    handle: *mut cglue::lv_theme_t,
+    config: Option<LvglThemeConfig>,
+    parent: Option<&'static LvglTheme>,
+    apply: Option<LvglThemeApply>,
+    styles: RefCell<Vec<&'static LvglStyle>>,
+}
+
+unsafe extern "C" fn theme_apply_cb(theme: *mut cglue::lv_theme_t, handle: *mut cglue::lv_obj_t) {
+    // lvgl walks the theme chain by itself, only apply this layer
+    let theme = &*((*theme).user_data as *const LvglTheme);
+    theme.apply_layer(handle);
+}
+
+impl LvglTheme {
+    // lvgl built-in theme, light or dark
+    pub fn new_default(
+        primary: LvglColor,
+        secondary: LvglColor,
+        dark: bool,
+        font: &'static LvglFont,
+    ) -> &'static Self {
+        Box::leak(Box::new(LvglTheme {
+            handle: std::ptr::null_mut(),
+            config: Some(LvglThemeConfig {
+                primary,
+                secondary,
+                dark,
+                font,
+            }),
+            parent: None,
+            apply: None,
+            styles: RefCell::new(Vec::new()),
+        }))
+    }
+
+    pub fn light(
+        primary: LvglColor,
+        secondary: LvglColor,
+        font: &'static LvglFont,
+    ) -> &'static Self {
+        LvglTheme::new_default(primary, secondary, false, font)
+    }
+
+    pub fn dark(
+        primary: LvglColor,
+        secondary: LvglColor,
+        font: &'static LvglFont,
+    ) -> &'static Self {
+        LvglTheme::new_default(primary, secondary, true, font)
+    }
+
+    // rust theme, apply closure is called for every created widget and for existing ones at activation
+    pub fn new_custom<F>(parent: &'static LvglTheme, apply: F) -> &'static Self
+    where
+        F: Fn(&LvglThemeTarget) + 'static,
+    {
+        unsafe {
+            let handle = Box::leak(Box::new(mem::zeroed::<cglue::lv_theme_t>()));
+            let theme = Box::leak(Box::new(LvglTheme {
+                handle,
+                config: None,
+                parent: Some(parent),
+                apply: Some(Box::new(apply)),
+                styles: RefCell::new(Vec::new()),
+            }));
+            handle.user_data = theme as *const _ as *mut raw::c_void;
+            theme
+        }
+    }
+
+    // black background, white text and thick yellow focus for outdoor readability
+    pub fn high_contrast(font: &'static LvglFont) -> &'static Self {
+        let base = LvglTheme::dark(LvglColor::YELLOW(), LvglColor::rvb(0xFF, 0xFF, 0xFF), font);
+
+        let main = LvglStyle::new();
+        main.set_background(LvglColor::rvb(0, 0, 0), cglue::LV_OPA_COVER as u8)
+            .set_text_color(LvglColor::rvb(0xFF, 0xFF, 0xFF))
+            .set_border_color(LvglColor::rvb(0xFF, 0xFF, 0xFF));
+
+        let button = LvglStyle::new();
+        button
+            .set_background(LvglColor::YELLOW(), cglue::LV_OPA_COVER as u8)
+            .set_text_color(LvglColor::rvb(0, 0, 0))
+            .set_border(3, LvglColor::rvb(0xFF, 0xFF, 0xFF))
+            .set_shadow_width(0);
+
+        let focus = LvglStyle::new();
+        focus.set_outline(4, 2, LvglColor::YELLOW());
+
+        LvglTheme::new_custom(base, move |target| {
+            if target.is_class(LvglClass::BUTTON) {
+                target.add_style(button, LvglPart::MAIN);
+            } else {
+                target.add_style(main, LvglPart::MAIN);
+            }
+            target.add_style(focus, LvglState::FOCUSED);
+            target.add_style(focus, LvglState::FOCUS_KEY);
+        })
+    }
+
+    pub fn get_active() -> Option<&'static LvglTheme> {
+        let active = ACTIVE_THEME.load(Ordering::Relaxed);
+        if active.is_null() {
+            None
+        } else {
+            Some(unsafe { &*active })
+        }
+    }
+
+    // restyle every existing widget and use theme for new ones
+    pub fn activate(&'static self) -> &'static Self {
+        if let Some(previous) = LvglTheme::get_active() {
+            previous.for_each_obj(&|handle| previous.remove_obj(handle));
+        }
+
+        let handle = self.prepare();
+        unsafe {
+            let display = cglue::lv_disp_get_default();
+            cglue::lv_disp_set_theme(display, handle);
+        }
+        ACTIVE_THEME.store(self as *const _ as *mut LvglTheme, Ordering::Relaxed);
+
+        self.for_each_obj(&|handle| self.apply_obj(handle));
+        unsafe { cglue::lv_obj_report_style_change(std::ptr::null_mut()) };
+        self
+    }
+
+    // initialize lvgl theme chain, default theme is shared and reconfigured each time
+    fn prepare(&self) -> *mut cglue::lv_theme_t {
+        match &self.config {
+            Some(config) => unsafe {
+                cglue::lv_theme_default_init(
+                    cglue::lv_disp_get_default(),
+                    config.primary.handle,
+                    config.secondary.handle,
+                    config.dark,
+                    config.font as *const _ as *const cglue::lv_font_t,
+                )
+            },
+            None => {
+                let parent = self.parent.map(|parent| parent.prepare());
+                unsafe {
+                    if let Some(parent) = parent {
+                        // keep colors & fonts from parent theme
+                        let handle = &mut *self.handle;
+                        handle.disp = (*parent).disp;
+                        handle.color_primary = (*parent).color_primary;
+                        handle.color_secondary = (*parent).color_secondary;
+                        handle.font_small = (*parent).font_small;
+                        handle.font_normal = (*parent).font_normal;
+                        handle.font_large = (*parent).font_large;
+                        handle.flags = (*parent).flags;
+                        cglue::lv_theme_set_parent(self.handle, parent);
+                    }
+                    cglue::lv_theme_set_apply_cb(self.handle, Some(theme_apply_cb));
+                }
+                self.handle
+            }
+        }
+    }
+
+    // custom layers from root to leaf, built-in themes only style objects at creation time
+    fn apply_obj(&'static self, handle: *mut cglue::lv_obj_t) {
+        if let Some(parent) = self.parent {
+            parent.apply_obj(handle);
+        }
+        self.apply_layer(handle);
+    }
+
+    fn apply_layer(&'static self, handle: *mut cglue::lv_obj_t) {
+        if let Some(apply) = &self.apply {
+            apply(&LvglThemeTarget {
+                handle,
+                theme: self,
+            });
+        }
+    }
+
+    fn remove_obj(&self, handle: *mut cglue::lv_obj_t) {
+        let mut theme = Some(self);
+        while let Some(current) = theme {
+            for style in current.styles.borrow().iter() {
+                unsafe {
+                    cglue::lv_obj_remove_style(
+                        handle,
+                        style.get_handle(),
+                        cglue::LV_PART_ANY | cglue::LV_STATE_ANY,
+                    );
+                }
+            }
+            theme = current.parent;
+        }
+    }
+
+    fn for_each_obj(&self, callback: &dyn Fn(*mut cglue::lv_obj_t)) {
+        fn walk(handle: *mut cglue::lv_obj_t, callback: &dyn Fn(*mut cglue::lv_obj_t)) {
+            callback(handle);
+            unsafe {
+                for idx in 0..cglue::lv_obj_get_child_cnt(handle) {
+                    walk(cglue::lv_obj_get_child(handle, idx as i32), callback);
+                }
+            }
+        }
+
+        unsafe {
+            let display = cglue::lv_disp_get_default();
+            for idx in 0..(*display).screen_cnt {
+                walk(*(*display).screens.add(idx as usize), callback);
+            }
+            walk(cglue::lv_disp_get_layer_top(display), callback);
+            walk(cglue::lv_disp_get_layer_sys(display), callback);
+        }
+    }
+}
@@ -16,6 +16,7 @@
  */
 
 use crate::prelude::*;
+use std::cell::Cell;
 use std::{thread, time};
 
 pub fn display_init() -> LvglHandle {
@@ -92,6 +93,175 @@ impl LvglHandler for SwitchEvtCtx {
     }
 }
 
+struct TableEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for TableEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let table = match widget.as_any().downcast_ref::<LvglTable>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        if let Some((row, column)) = table.get_selected_cell() {
+            println!(
+                "Table-Callback {{app_data:{} widget:{}, 'event':{:?} 'cell':[{},{}] 'value':{:?}}}",
+                self.app_data,
+                uid,
+                event,
+                row,
+                column,
+                table.get_cell(row, column)
+            );
+        }
+    }
+}
+
+struct MsgboxEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for MsgboxEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let msgbox = match widget.as_any().downcast_ref::<LvglMsgbox>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        println!(
+            "Msgbox-Callback {{app_data:{} widget:{}, 'event':{:?} 'button':{:?} 'text':{:?}}}",
+            self.app_data,
+            uid,
+            event,
+            msgbox.get_active_button(),
+            msgbox.get_active_text()
+        );
+        msgbox.close();
+    }
+}
+
+struct CalendarEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for CalendarEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let calendar = match widget.as_any().downcast_ref::<LvglCalendar>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        if let Some(date) = calendar.get_pressed_date() {
+            println!(
+                "Calendar-Callback {{app_data:{} widget:{}, 'event':{:?} 'date':{}/{}/{}}}",
+                self.app_data, uid, event, date.year, date.month, date.day
+            );
+        }
+    }
+}
+
+struct EntryEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for EntryEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        // uid is the clicked entry, not the list/menu widget
+        println!(
+            "Entry-Callback {{app_data:{} widget:{} entry:{}, 'event':{:?}}}",
+            self.app_data,
+            widget.get_uid(),
+            uid,
+            event
+        );
+    }
+}
+
+struct SpinboxEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for SpinboxEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let spinbox = match widget.as_any().downcast_ref::<LvglSpinbox>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        println!(
+            "Spinbox-Callback {{app_data:{} widget:{}, 'event':{:?} 'raw':{} 'value':{}}}",
+            self.app_data,
+            uid,
+            event,
+            spinbox.get_value(),
+            spinbox.get_fixed()
+        );
+    }
+}
+
+struct ImgButtonEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for ImgButtonEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let button = match widget.as_any().downcast_ref::<LvglImgButton>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        println!(
+            "ImgButton-Callback {{app_data:{} widget:{}, 'event':{:?} 'checked':{}}}",
+            self.app_data,
+            uid,
+            event,
+            button.is_checked()
+        );
+    }
+}
+
+struct GifEvtCtx {
+    app_data: &'static str,
+}
+impl LvglHandler for GifEvtCtx {
+    fn callback(&self, widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        let gif = match widget.as_any().downcast_ref::<LvglGif>() {
+            Some(widget) => widget,
+            None => return,
+        };
+
+        println!(
+            "Gif-Callback {{app_data:{} widget:{}, 'event':{:?}}}",
+            self.app_data, uid, event
+        );
+        gif.restart();
+    }
+}
+
+struct ThemeEvtCtx {
+    themes: [&'static LvglTheme; 3],
+    index: Cell<usize>,
+}
+
+impl LvglHandler for ThemeEvtCtx {
+    fn callback(&self, _widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        if let LvglEvent::CLICKED = event {
+            let index = (self.index.get() + 1) % self.themes.len();
+            self.index.set(index);
+            self.themes[index].activate();
+            println!("Theme-Callback {{widget:{} theme:{}}}", uid, index);
+        }
+    }
+}
+
+struct AnimEvtCtx {
+    led: &'static LvglLed,
+}
+
+impl LvglHandler for AnimEvtCtx {
+    fn callback(&self, _widget: &LvglWidget, uid: &'static str, event: &LvglEvent) {
+        if let LvglEvent::CLICKED = event {
+            let cancelled = LvglAnimation::cancel(self.led);
+            println!("Anim-Callback {{widget:{} cancelled:{}}}", uid, cancelled);
+        }
+    }
+}
+
 pub fn draw_date(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
     let date = get_time("%D %H:%M");
     LvglLabel::new(root, "Local-Time", LvglMkFont::std_14(), x_ofs, y_ofs)
@@ -237,12 +407,381 @@ pub fn draw_arc(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
 
 pub fn draw_tux(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
     let tux_path = PRJ_DIR.to_string() + "/assets/tux-evsex250.png";
-    LvglImage::new(root, "tux-evse", tux_path.as_str(), x_ofs, y_ofs).set_title(
-        "tux-evse mascot",
-        65,
-        0,
+    match LvglImage::new(root, "tux-evse", tux_path.as_str(), x_ofs, y_ofs) {
+        Err(error) => println!("tux-evse image fail error={}", error),
+        Ok(image) => {
+            image.set_title("tux-evse mascot", 65, 0, LvglMkFont::std_14());
+        }
+    }
+}
+
+#[cfg(feature = "png")]
+pub fn draw_decoders(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    static TUX_PNG: &[u8] = include_bytes!("../assets/tux-evsex150.png");
+    LvglImage::from_data(root, "tux-data", TUX_PNG, x_ofs, y_ofs)
+        .expect("embedded png should decode")
+        .set_info("in memory png");
+
+    // unknown format should be reported instead of drawing an empty widget
+    let bad_path = PRJ_DIR.to_string() + "/Cargo.toml";
+    match LvglImage::new(root, "bad-image", bad_path.as_str(), x_ofs + 200, y_ofs) {
+        Err(error) => println!("expected decoding error={}", error),
+        Ok(_) => panic!("Cargo.toml should not decode as image"),
+    }
+}
+
+#[cfg(feature = "png")]
+pub fn draw_filesystem(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let assets = PRJ_DIR.to_string() + "/assets";
+    let drive = LvglFsDriver::register('A', assets.as_str()).expect("assets drive registration");
+
+    LvglImage::new(root, "tux-drive", "A:/tux-evsex150.png", x_ofs, y_ofs)
+        .expect("image from assets drive")
+        .set_info("image loaded through rust fs driver");
+
+    // escaping driver root is refused and reported as filesystem error, decoders select files
+    // by extension, use an image one to reach the driver open callback
+    if LvglImage::new(root, "tux-escape", "A:/../escape.png", x_ofs, y_ofs).is_ok() {
+        panic!("path outside of driver root should fail");
+    }
+    match drive.take_error() {
+        Some(LvglError::Filesystem(error)) => println!("expected fs error={}", error),
+        _ => panic!("missing filesystem error"),
+    }
+}
+
+pub fn draw_static_png(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    // converted by build.rs, no decoder nor filesystem involved at runtime
+    LvglPixmap::new(
+        root,
+        "tux-static",
+        crate::lvgl_image!("assets/tux-evsex150.png"),
+        x_ofs,
+        y_ofs,
+    )
+    .set_info("build time converted png");
+}
+
+pub fn draw_image_buffer(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let buffer =
+        LvglImageBuffer::new(64, 64, LvglColorFormat::TRUE_COLOR_ALPHA).expect("image buffer size");
+    buffer.fill(LvglColor::DEEP_PURPLE(), 128);
+    // last pixel is outside of buffer and ignored
+    for idx in 0..65 {
+        buffer.set_pixel(idx, idx, LvglColor::YELLOW());
+    }
+
+    // same buffer shown twice, both widgets are redrawn on update
+    LvglPixmap::new(root, "buffer-pixmap", LvglIcon::IMAGE, x_ofs, y_ofs)
+        .set_buffer(&buffer)
+        .set_info("rust owned pixels");
+    LvglPixButton::new(root, "buffer-button", x_ofs + 100, y_ofs)
+        .set_buffer(&buffer)
+        .set_size(80, 80)
+        .finalize();
+
+    let size = buffer.get_width() as usize * buffer.get_height() as usize;
+    buffer.update(|pixels| {
+        let stride = pixels.len() / size;
+        for pixel in pixels.chunks_mut(stride).take(size / 2) {
+            pixel[stride - 1] = 0xFF; // opaque top half
+        }
+    });
+
+    let wrong = vec![0; 10];
+    assert!(buffer.set_pixels(&wrong).is_err());
+    assert!(LvglImageBuffer::new(40000, 10, LvglColorFormat::TRUE_COLOR).is_err());
+}
+
+pub fn draw_shared_style(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let branding = LvglStyle::new();
+    branding
+        .set_background(LvglColor::DEEP_PURPLE(), cglue::LV_OPA_COVER as u8)
+        .set_border(2, LvglColor::AMBER())
+        .set_text_color(LvglColor::rvb(0xFF, 0xFF, 0xFF))
+        .set_text_font(LvglMkFont::std_18())
+        .set_padding(10, 10, 20, 20);
+
+    LvglButton::new(root, "Style-A", LvglMkFont::std_14(), x_ofs, y_ofs)
+        .set_value("Branded-A")
+        .add_style(branding, LvglPart::MAIN);
+    LvglButton::new(root, "Style-B", LvglMkFont::std_14(), x_ofs + 200, y_ofs)
+        .set_value("Branded-B")
+        .add_style(branding, LvglPart::MAIN);
+    LvglLabel::new(root, "Style-C", LvglMkFont::std_14(), x_ofs, y_ofs + 80)
+        .set_value("Branded label")
+        .add_style(branding, LvglPart::MAIN);
+
+    // every widget using the style gets refreshed
+    branding.set_border(4, LvglColor::LIGHT_GREEN());
+}
+
+pub fn draw_selectors(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    LvglButton::new(root, "Select-Button", LvglMkFont::std_14(), x_ofs, y_ofs)
+        .set_value("Press me")
+        .set_style_bg_color(LvglColor::BLUE(), LvglState::DEFAULT)
+        .set_style_bg_color(LvglColor::RED(), LvglState::PRESSED)
+        .set_style_border_width(4, LvglPart::MAIN | LvglState::FOCUSED);
+
+    LvglSwitch::new(root, "Select-Switch", x_ofs + 200, y_ofs)
+        .set_style_bg_color(LvglColor::GREEN(), LvglPart::INDICATOR | LvglState::CHECKED)
+        .set_style_bg_color(LvglColor::AMBER(), LvglPart::KNOB | LvglState::CHECKED)
+        .set_style_bg_color(
+            LvglColor::GREY(),
+            LvglPart::KNOB | LvglState::CHECKED | LvglState::DISABLED,
+        );
+
+    LvglBar::new(root, "Select-Bar", 0, 100, x_ofs, y_ofs + 80)
+        .set_value(60)
+        .set_style_bg_color(LvglColor::TEAL(), LvglPart::INDICATOR)
+        .set_style_bg_opa(cglue::LV_OPA_50 as u8, LvglPart::MAIN);
+
+    // historical style helpers with an explicit part/state
+    LvglButton::new(
+        root,
+        "Select-Legacy",
         LvglMkFont::std_14(),
+        x_ofs + 200,
+        y_ofs + 80,
+    )
+    .set_value("Focus me")
+    .set_color_for(LvglColor::YELLOW(), LvglState::PRESSED)
+    .set_border_for(3, LvglColor::AMBER(), LvglState::FOCUSED)
+    .set_padding_for(12, 12, 20, 20, LvglState::PRESSED)
+    .set_radius_for(4, LvglPart::MAIN)
+    .set_background_for(
+        LvglColor::INDIGO(),
+        cglue::LV_OPA_COVER as u8,
+        LvglState::PRESSED,
     );
+    LvglBar::new(root, "Select-Gradient", 0, 100, x_ofs, y_ofs + 130)
+        .set_value(40)
+        .set_gradient_for(
+            false,
+            LvglColor::GREY(),
+            LvglColor::BLUE_GREY(),
+            LvglPart::MAIN,
+        )
+        .set_gradient(false, LvglColor::GREEN(), LvglColor::YELLOW());
+}
+
+pub fn draw_transition(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let props = [LvglStyleProp::BG_COLOR, LvglStyleProp::BORDER_WIDTH];
+    let press = LvglTransition::new(&props, 300, 0, LvglEasing::OVERSHOOT);
+    let release = LvglTransition::new(&props, 500, 100, LvglEasing::EASE_OUT);
+
+    LvglButton::new(
+        root,
+        "Transition-Button",
+        LvglMkFont::std_14(),
+        x_ofs,
+        y_ofs,
+    )
+    .set_value("Smooth")
+    .set_style_bg_color(LvglColor::RED(), LvglState::PRESSED)
+    .set_style_border_width(8, LvglState::PRESSED)
+    .set_transition(press, LvglState::PRESSED)
+    .set_transition(release, LvglState::DEFAULT);
+}
+
+pub fn draw_extended_style(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let card = LvglStyle::new();
+    card.set_background(LvglColor::rvb(0xFF, 0xFF, 0xFF), cglue::LV_OPA_COVER as u8)
+        .set_radius(12)
+        .set_clip_corner(true)
+        .set_shadow(20, 4, 6, 2, LvglColor::BLUE_GREY())
+        .set_shadow_opa(cglue::LV_OPA_50 as u8)
+        .set_outline(2, 4, LvglColor::LIGHT_BLUE())
+        .set_text_letter_space(2)
+        .set_text_line_space(6)
+        .set_text_align(LvglTextAlign::CENTER)
+        .set_text_decor(LvglTextDecor::UNDERLINE);
+
+    LvglLabel::new(root, "Card-Label", LvglMkFont::std_18(), x_ofs, y_ofs)
+        .set_value("Designer card\nwith shadow")
+        .set_size(220, 80)
+        .add_style(card, LvglPart::MAIN);
+
+    LvglButton::new(
+        root,
+        "Card-Button",
+        LvglMkFont::std_14(),
+        x_ofs + 260,
+        y_ofs,
+    )
+    .set_value("Rotate")
+    .set_style_opa(cglue::LV_OPA_80 as u8, LvglState::DEFAULT)
+    .set_style_radius(4, LvglState::DEFAULT)
+    .set_style_bg_img(LvglIcon::OK, LvglState::DEFAULT)
+    .set_transform(300, 150, 50, 20, LvglState::PRESSED)
+    .set_outline(3, 2, LvglColor::AMBER(), LvglState::FOCUSED);
+}
+
+pub fn draw_gradient(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let sunset = LvglStyle::new();
+    sunset
+        .set_bg_opa(cglue::LV_OPA_COVER as u8)
+        .set_gradient(
+            LvglGradDir::VER,
+            LvglColor::DEEP_ORANGE(),
+            LvglColor::DEEP_PURPLE(),
+            64,
+            192,
+        )
+        .set_bg_dither(LvglDither::ORDERED);
+
+    LvglArea::new(root, "Grad-Area", x_ofs, y_ofs)
+        .set_size(200, 120)
+        .add_style(sunset, LvglPart::MAIN);
+
+    LvglBar::new(root, "Grad-Bar", 0, 100, x_ofs + 250, y_ofs)
+        .set_value(80)
+        .set_style_gradient(
+            LvglGradDir::HOR,
+            LvglColor::GREEN(),
+            LvglColor::RED(),
+            LvglPart::INDICATOR,
+        );
+
+    // multi stops requires LV_GRADIENT_MAX_STOPS >= 3
+    match LvglGradient::new(
+        LvglGradDir::HOR,
+        &[
+            (LvglColor::BLUE(), 0),
+            (LvglColor::rvb(0xFF, 0xFF, 0xFF), 128),
+            (LvglColor::RED(), 255),
+        ],
+    ) {
+        Ok(gradient) => {
+            LvglArea::new(root, "Grad-Multi", x_ofs, y_ofs + 150)
+                .set_size(200, 60)
+                .set_style_bg_opa(cglue::LV_OPA_COVER as u8, LvglPart::MAIN)
+                .set_style_bg_grad(gradient, LvglPart::MAIN);
+        }
+        Err(error) => println!("multi stops gradient unsupported error={}", error),
+    }
+}
+
+pub fn draw_theme(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let day = LvglTheme::light(LvglColor::BLUE(), LvglColor::AMBER(), LvglMkFont::std_14());
+    let night = LvglTheme::dark(LvglColor::INDIGO(), LvglColor::TEAL(), LvglMkFont::std_14());
+
+    // rounded evse branding on top of night theme
+    let rounded = LvglStyle::new();
+    rounded.set_radius(20).set_border(2, LvglColor::TEAL());
+    let branded = LvglTheme::new_custom(night, move |target| {
+        if target.is_class(LvglClass::BUTTON) {
+            target.add_style(rounded, LvglPart::MAIN);
+        }
+    });
+    day.activate();
+
+    LvglButton::new(root, "Theme-Button", LvglMkFont::std_14(), x_ofs, y_ofs)
+        .set_value("Day/Night")
+        .set_callback(Box::leak(Box::new(ThemeEvtCtx {
+            themes: [day, night, branded],
+            index: Cell::new(0),
+        })));
+    LvglSwitch::new(root, "Theme-Switch", x_ofs + 200, y_ofs);
+    LvglLabel::new(root, "Theme-Label", LvglMkFont::std_14(), x_ofs, y_ofs + 80)
+        .set_value("Click button to switch theme");
+
+    // outdoor mode, widgets created before activation get restyled
+    LvglTheme::high_contrast(LvglMkFont::std_18()).activate();
+    day.activate();
+}
+
+#[cfg(feature = "stylesheet")]
+pub fn draw_stylesheet(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let sheet_path = PRJ_DIR.to_string() + "/assets/evse-style.toml";
+    let sheet = match LvglStyleSheet::load(sheet_path.as_str()) {
+        Ok(value) => value,
+        Err(error) => panic!("fail to load style sheet error={}", error),
+    };
+    // edit evse-style.toml while test is running to restyle widgets
+    #[cfg(debug_assertions)]
+    sheet.watch(1000, |error| {
+        println!("style sheet reload fail error={}", error)
+    });
+
+    LvglArea::new(root, "Sheet-Panel", x_ofs, y_ofs)
+        .set_size(300, 160)
+        .add_class(sheet, "panel", LvglPart::MAIN);
+    LvglLabel::new(
+        root,
+        "Sheet-Title",
+        LvglMkFont::std_14(),
+        x_ofs + 20,
+        y_ofs + 20,
+    )
+    .set_value("Style sheet")
+    .add_class(sheet, "title", LvglPart::MAIN);
+    LvglButton::new(
+        root,
+        "Sheet-Button",
+        LvglMkFont::std_14(),
+        x_ofs + 20,
+        y_ofs + 80,
+    )
+    .set_value("Charge")
+    .add_class(sheet, "button", LvglPart::MAIN)
+    .add_class(sheet, "unknown", LvglPart::MAIN);
+}
+
+pub fn draw_animation(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    // blinking led until button is clicked
+    let led = LvglLed::new(root, "Anim-Led", x_ofs, y_ofs);
+    led.set_color(LvglColor::GREEN()).set_on(true);
+    LvglAnimation::new(led, LvglAnimProp::OPA, 255, 50)
+        .set_duration(600)
+        .set_playback(600, 100)
+        .set_repeat(LvglAnimation::INFINITE, 0)
+        .set_easing(LvglEasing::EASE_IN_OUT)
+        .start();
+
+    // sliding label with a completion callback
+    let label = LvglLabel::new(root, "Anim-Label", LvglMkFont::std_14(), x_ofs, y_ofs + 50);
+    label.set_value("Sliding label");
+    LvglAnimation::new(label, LvglAnimProp::X, x_ofs as i32, x_ofs as i32 + 200)
+        .set_duration(1500)
+        .set_delay(500)
+        .set_easing(LvglEasing::BOUNCE)
+        .set_ready_cb(|| println!("Anim-Label ready"))
+        .start();
+
+    // custom rust setter driving bar value
+    let bar = LvglBar::new(root, "Anim-Bar", 0, 100, x_ofs, y_ofs + 100);
+    LvglAnimation::with_setter(bar, 0, 100, move |value| {
+        bar.set_value(value);
+    })
+    .set_duration(2000)
+    .set_playback(2000, 0)
+    .set_repeat(3, 500)
+    .start();
+
+    // any numeric style property
+    let area = LvglArea::new(root, "Anim-Area", x_ofs + 300, y_ofs);
+    area.set_size(100, 100);
+    LvglAnimation::new(
+        area,
+        LvglAnimProp::STYLE(LvglStyleProp::RADIUS, LvglPart::MAIN.into()),
+        0,
+        50,
+    )
+    .set_duration(1000)
+    .set_playback(1000, 0)
+    .set_repeat(LvglAnimation::INFINITE, 200)
+    .start();
+
+    LvglButton::new(
+        root,
+        "Anim-Button",
+        LvglMkFont::std_14(),
+        x_ofs,
+        y_ofs + 160,
+    )
+    .set_value("Stop led")
+    .set_callback(Box::leak(Box::new(AnimEvtCtx { led })));
 }
 
 pub fn draw_qrcode(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
@@ -306,6 +845,353 @@ pub fn draw_area(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
         .finalize();
 }
 
+pub fn draw_chart(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let chart = LvglChart::new(root, "Chart", LvglChartType::LINE, 10, x_ofs, y_ofs)
+        .set_size(300, 150)
+        .set_div_lines(5, 10)
+        .set_range(LvglChartAxis::PRIMARY_Y, 0, 100)
+        .set_range(LvglChartAxis::SECONDARY_Y, 0, 500)
+        .set_axis_tick(LvglChartAxis::PRIMARY_Y, 10, 5, 6, 2, true, 50)
+        .set_axis_tick(LvglChartAxis::PRIMARY_X, 10, 5, 5, 1, true, 30)
+        .set_axis_labels(LvglChartAxis::PRIMARY_X, &["0s", "2s", "4s", "6s", "8s"])
+        .add_series(LvglColor::RED(), LvglChartAxis::PRIMARY_Y)
+        .add_series(LvglColor::GREEN(), LvglChartAxis::SECONDARY_Y)
+        .set_values(1, &[10, 50, 90, 140, 200, 260, 300, 350, 420, 480]);
+
+    for value in [10, 30, 25, 60, 75, 70, 90, 40, 55, 20] {
+        chart.push_value(0, value);
+    }
+}
+
+pub fn draw_table(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let sessions = vec![
+        vec![
+            "Session".to_string(),
+            "Energy".to_string(),
+            "Duration".to_string(),
+        ],
+        vec!["#1".to_string(), "12.5 kWh".to_string(), "1h20".to_string()],
+        vec!["#2".to_string(), "7.1 kWh".to_string(), "0h45".to_string()],
+        vec!["Total".to_string(), "19.6 kWh".to_string()],
+    ];
+
+    LvglTable::new(root, "Table", 0, 0, x_ofs, y_ofs)
+        .set_info("charging sessions")
+        .set_values(&sessions)
+        .set_col_width(0, 100)
+        .set_col_width(1, 120)
+        .set_col_width(2, 100)
+        .add_cell_ctrl(3, 1, LvglTableCtrl::MERGE_RIGHT)
+        .set_callback(Box::leak(Box::new(TableEvtCtx {
+            app_data: "Draw-Table",
+        })))
+        .finalize();
+}
+
+pub fn draw_tabview(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let tabview = LvglTabview::new(root, "Tabview", LvglDirection::TOP, 40, x_ofs, y_ofs)
+        .set_info("settings tabs")
+        .set_size(400, 250)
+        // tab pages without own handler report clicks to tabview handler
+        .set_callback(Box::leak(Box::new(EntryEvtCtx {
+            app_data: "Draw-Tabview",
+        })));
+
+    let network = tabview.add_tab("Tab-Network", "Network");
+    LvglLabel::new(network, "Tab-Network-Label", LvglMkFont::std_14(), 10, 10)
+        .set_value("Network settings");
+    LvglSwitch::new(network, "Tab-Network-Wifi", 10, 50);
+
+    let energy = tabview.add_tab("Tab-Energy", "Energy");
+    LvglLabel::new(energy, "Tab-Energy-Label", LvglMkFont::std_14(), 10, 10)
+        .set_value("Energy settings");
+    LvglBar::new(energy, "Tab-Energy-Bar", 0, 100, 10, 50)
+        .set_size(200, 10)
+        .set_value(30);
+
+    tabview.set_active(1, false);
+}
+
+pub fn draw_tileview(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let tileview = LvglTileview::new(root, "Tileview", x_ofs, y_ofs).set_size(300, 200);
+
+    let home = tileview.add_tile("Tile-Home", 0, 0, &[LvglDirection::RIGHT]);
+    LvglLabel::new(home, "Tile-Home-Label", LvglMkFont::std_18(), 10, 10).set_value("Swipe left");
+
+    let status = tileview.add_tile(
+        "Tile-Status",
+        1,
+        0,
+        &[LvglDirection::LEFT, LvglDirection::BOTTOM],
+    );
+    LvglLabel::new(status, "Tile-Status-Label", LvglMkFont::std_18(), 10, 10)
+        .set_value("Status tile");
+}
+
+// msgbox is modal and always centered on top layer
+pub fn draw_msgbox() {
+    LvglMsgbox::new(
+        "Msgbox-Stop",
+        "Charging",
+        "Stop charging?",
+        &["Yes", "No"],
+        true,
+    )
+    .set_info("stop charging confirmation")
+    .set_callback(Box::leak(Box::new(MsgboxEvtCtx {
+        app_data: "Draw-Msgbox",
+    })))
+    .finalize();
+}
+
+pub fn draw_notify() {
+    LvglToast::start(300, 3000, 5).set_fade(800);
+
+    // notifications may come from any thread
+    thread::spawn(|| {
+        let levels = [LvglLevel::INFO, LvglLevel::WARNING, LvglLevel::ERROR];
+        let mut count = 0;
+        loop {
+            let level = levels[count % levels.len()];
+            notify(
+                level,
+                format!("Notification #{} {:?}", count, level).as_str(),
+            );
+            count += 1;
+            thread::sleep(time::Duration::from_millis(700));
+        }
+    });
+}
+
+pub fn draw_spinner(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    LvglSpinner::new(root, "Spinner", 1000, 60, x_ofs, y_ofs)
+        .set_info("waiting authorization")
+        .set_size(100, 100)
+        .set_width(8)
+        .set_color(LvglColor::DEEP_ORANGE())
+        .finalize();
+}
+
+pub fn draw_busy() {
+    LvglBusy::new(LvglMkFont::std_18())
+        .set_color(LvglColor::LIGHT_BLUE())
+        .raise(Some("Authorization in progress..."));
+}
+
+pub fn draw_calendar(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let highlighted = [
+        LvglDate {
+            year: 2023,
+            month: 6,
+            day: 14,
+        },
+        LvglDate {
+            year: 2023,
+            month: 6,
+            day: 21,
+        },
+    ];
+
+    LvglCalendar::new(root, "Calendar", x_ofs, y_ofs)
+        .set_size(300, 300)
+        .set_today(2023, 6, 12)
+        .set_showed(2023, 6)
+        .set_highlighted(&highlighted)
+        .set_header_arrow()
+        .set_callback(Box::leak(Box::new(CalendarEvtCtx {
+            app_data: "Draw-Calendar",
+        })))
+        .finalize();
+
+    // compact date picker attached to a text area
+    let textarea = LvglTextArea::new(root, "Date-Picker", x_ofs + 350, y_ofs).set_width(200);
+    LvglCalendar::new(root, "Calendar-Popup", 0, 0)
+        .set_size(250, 250)
+        .set_today_now()
+        .set_header_dropdown()
+        .set_textarea(textarea);
+}
+
+pub fn draw_list(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    LvglList::new(root, "List", x_ofs, y_ofs)
+        .set_size(250, 300)
+        .add_text("Network")
+        .add_button("List-Wifi", Some(LvglIcon::WIFI), "Wifi")
+        .add_button("List-Bluetooth", Some(LvglIcon::BLUETOOTH), "Bluetooth")
+        .add_text("Maintenance")
+        .add_button("List-Update", Some(LvglIcon::DOWNLOAD), "Update")
+        .add_button("List-Reboot", Some(LvglIcon::POWER), "Reboot")
+        .set_callback(Box::leak(Box::new(EntryEvtCtx {
+            app_data: "Draw-List",
+        })))
+        .finalize();
+}
+
+pub fn draw_menu(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let menu = LvglMenu::new(root, "Menu", x_ofs, y_ofs)
+        .set_size(500, 300)
+        .set_back_button(true);
+
+    let network = menu.add_page("Menu-Network", Some("Network"));
+    let section = menu.add_section("Menu-Network-Section", network);
+    menu.add_entry(section, "Menu-Wifi", Some(LvglIcon::WIFI), "Wifi", None)
+        .add_entry(
+            section,
+            "Menu-Ethernet",
+            Some(LvglIcon::DRIVE),
+            "Ethernet",
+            None,
+        );
+
+    let about = menu.add_page("Menu-About", Some("About"));
+    menu.add_entry(about, "Menu-Version", None, "Version 0.0.1", None);
+
+    let root_page = menu.add_page("Menu-Root", Some("Settings"));
+    let section = menu.add_section("Menu-Root-Section", root_page);
+    menu.add_entry(
+        section,
+        "Menu-Network-Entry",
+        Some(LvglIcon::SETTINGS),
+        "Network",
+        Some(network),
+    )
+    .add_separator(root_page)
+    .add_entry(
+        root_page,
+        "Menu-About-Entry",
+        Some(LvglIcon::LIST),
+        "About",
+        Some(about),
+    )
+    .set_root(root_page)
+    .set_callback(Box::leak(Box::new(EntryEvtCtx {
+        app_data: "Draw-Menu",
+    })))
+    .finalize();
+}
+
+pub fn draw_spinbox(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    // current limit from 6.0A to 32.0A with 0.5A step
+    LvglSpinbox::new(root, "Spinbox-Current", 3, 2, x_ofs, y_ofs)
+        .set_info("max current")
+        .set_width(100)
+        .set_range(60, 320)
+        .set_step(5)
+        .set_fixed(16.0)
+        .set_buttons(root, LvglMkFont::std_14())
+        .set_callback(Box::leak(Box::new(SpinboxEvtCtx {
+            app_data: "Draw-Spinbox",
+        })))
+        .finalize();
+}
+
+pub fn draw_canvas(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    // sparkline over a connector like shape
+    let sparkline = [
+        LvglPoint { x: 10, y: 140 },
+        LvglPoint { x: 50, y: 110 },
+        LvglPoint { x: 90, y: 125 },
+        LvglPoint { x: 130, y: 80 },
+        LvglPoint { x: 170, y: 95 },
+        LvglPoint { x: 210, y: 40 },
+    ];
+    let plug = [
+        LvglPoint { x: 230, y: 20 },
+        LvglPoint { x: 280, y: 20 },
+        LvglPoint { x: 270, y: 60 },
+        LvglPoint { x: 240, y: 60 },
+    ];
+
+    let canvas = LvglCanvas::new(root, "Canvas", 300, 160, x_ofs, y_ofs)
+        .fill(LvglColor::rvb(0xFF, 0xFF, 0xFF))
+        .draw_rect(5, 5, 290, 150, 10, LvglColor::GREY())
+        .draw_line(&sparkline, 3, LvglColor::GREEN())
+        .draw_polygon(&plug, LvglColor::BLUE_GREY())
+        .draw_arc(255, 100, 25, 0, 270, 5, LvglColor::ORANGE())
+        .draw_text(
+            10,
+            10,
+            200,
+            "Power (kW)",
+            LvglMkFont::std_14(),
+            LvglColor::RED(),
+        )
+        .set_pixel(150, 150, LvglColor::RED())
+        // out of canvas pixels are ignored
+        .set_pixel(300, 150, LvglColor::RED());
+
+    assert!(canvas.get_pixel(150, 150).is_some());
+    assert!(canvas.get_pixel(-1, 0).is_none());
+    assert!(canvas.get_pixel(0, 160).is_none());
+    canvas.finalize();
+}
+
+pub fn draw_imgbutton(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    LvglImgButton::new(root, "ImgButton-Start", LvglMkFont::std_14(), x_ofs, y_ofs)
+        .set_default_skin()
+        .set_value("Start")
+        .set_width(120)
+        .set_callback(Box::leak(Box::new(ImgButtonEvtCtx {
+            app_data: "Draw-ImgButton",
+        })))
+        .finalize();
+
+    LvglImgButton::new(
+        root,
+        "ImgButton-Toggle",
+        LvglMkFont::std_14(),
+        x_ofs,
+        y_ofs + 70,
+    )
+    .set_default_skin()
+    .set_value("Toggle")
+    .set_width(120)
+    .set_checkable(true)
+    .set_callback(Box::leak(Box::new(ImgButtonEvtCtx {
+        app_data: "Draw-ImgButton",
+    })))
+    .finalize();
+}
+
+pub fn draw_animimg(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    let charging = [
+        LvglIcon::BATTERY_EMPTY,
+        LvglIcon::BATTERY_1,
+        LvglIcon::BATTERY_2,
+        LvglIcon::BATTERY_3,
+        LvglIcon::BATTERY_FULL,
+    ];
+
+    LvglAnimImage::new(root, "Anim-Charging", x_ofs, y_ofs)
+        .set_info("charging in progress")
+        .set_frames(&charging)
+        .unwrap()
+        .set_duration(2000)
+        .set_repeat(None)
+        .start()
+        .finalize();
+
+    let plug = LvglAnimImage::new(root, "Anim-Plug", x_ofs + 50, y_ofs);
+    // lvgl frame count is u8
+    assert!(plug.set_frames(&[LvglIcon::USB; 256]).is_err());
+    plug.set_frames(&[LvglIcon::CHARGE, LvglIcon::USB])
+        .unwrap()
+        .set_duration(1000)
+        .set_repeat(Some(10))
+        .start()
+        .finalize();
+}
+
+pub fn draw_gif(root: &LvglWidget, x_ofs: i16, y_ofs: i16) {
+    LvglGif::new(root, "Gif-Charging", x_ofs, y_ofs)
+        .set_info("idle marketing loop")
+        .set_data(include_bytes!("../assets/charging-x64.gif"))
+        .set_callback(Box::leak(Box::new(GifEvtCtx {
+            app_data: "Draw-Gif",
+        })))
+        .finalize();
+}
+
 #[test]
 pub fn test_label() {
     let root = display_init().get_root_widget();
@@ -400,6 +1286,206 @@ pub fn test_area() {
     display_loop();
 }
 
+#[test]
+pub fn test_chart() {
+    let root = display_init().get_root_widget();
+    draw_chart(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_table() {
+    let root = display_init().get_root_widget();
+    draw_table(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_tabview() {
+    let root = display_init().get_root_widget();
+    draw_tabview(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_tileview() {
+    let root = display_init().get_root_widget();
+    draw_tileview(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_msgbox() {
+    let _root = display_init().get_root_widget();
+    draw_msgbox();
+    display_loop();
+}
+
+#[test]
+pub fn test_notify() {
+    let _root = display_init().get_root_widget();
+    draw_notify();
+    display_loop();
+}
+
+#[test]
+pub fn test_spinner() {
+    let root = display_init().get_root_widget();
+    draw_spinner(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_busy() {
+    let root = display_init().get_root_widget();
+    draw_button(root, 450, 200);
+    draw_busy();
+    display_loop();
+}
+
+#[test]
+pub fn test_calendar() {
+    let root = display_init().get_root_widget();
+    draw_calendar(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_list() {
+    let root = display_init().get_root_widget();
+    draw_list(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_menu() {
+    let root = display_init().get_root_widget();
+    draw_menu(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_spinbox() {
+    let root = display_init().get_root_widget();
+    draw_spinbox(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_canvas() {
+    let root = display_init().get_root_widget();
+    draw_canvas(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_imgbutton() {
+    let root = display_init().get_root_widget();
+    draw_imgbutton(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_animimg() {
+    let root = display_init().get_root_widget();
+    draw_animimg(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_gif() {
+    let root = display_init().get_root_widget();
+    draw_gif(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+#[cfg(feature = "png")]
+pub fn test_decoders() {
+    let root = display_init().get_root_widget();
+    draw_decoders(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+#[cfg(feature = "png")]
+pub fn test_filesystem() {
+    let root = display_init().get_root_widget();
+    draw_filesystem(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_static_png() {
+    let root = display_init().get_root_widget();
+    draw_static_png(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_image_buffer() {
+    let root = display_init().get_root_widget();
+    draw_image_buffer(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_shared_style() {
+    let root = display_init().get_root_widget();
+    draw_shared_style(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_selectors() {
+    let root = display_init().get_root_widget();
+    draw_selectors(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_transition() {
+    let root = display_init().get_root_widget();
+    draw_transition(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_extended_style() {
+    let root = display_init().get_root_widget();
+    draw_extended_style(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_gradient() {
+    let root = display_init().get_root_widget();
+    draw_gradient(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_theme() {
+    let root = display_init().get_root_widget();
+    draw_theme(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+#[cfg(feature = "stylesheet")]
+pub fn test_stylesheet() {
+    let root = display_init().get_root_widget();
+    draw_stylesheet(root, 100, 100);
+    display_loop();
+}
+
+#[test]
+pub fn test_animation() {
+    let root = display_init().get_root_widget();
+    draw_animation(root, 100, 100);
+    display_loop();
+}
+
 #[test]
 pub fn test_pannel() {
     let primary = LvglColor::LIGHT_BLUE();